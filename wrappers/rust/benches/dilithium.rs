@@ -0,0 +1,74 @@
+//! Criterion benchmarks for the Dilithium signature operations.
+//!
+//! Modeled on libcrux's `kyber768.rs` bench: keygen, signing and
+//! verification are each measured independently, with signing and
+//! verification additionally swept across a few representative message
+//! sizes since Dilithium's cost scales with the message being hashed.
+//! `BatchSize::SmallInput` forces a fresh key pair / signature per
+//! measured iteration rather than reusing one across a batch. Throughput
+//! is reported in bytes of message processed per second.
+//!
+//! Gated behind the `bench` feature (`cargo bench --features bench`), so
+//! an ordinary `cargo build`/`cargo test` does not build this bench target.
+
+use criterion::{black_box, criterion_group, criterion_main, BatchSize, BenchmarkId, Criterion, Throughput};
+use pqchub::{Dilithium2, Dilithium3, Dilithium5};
+
+/// Representative message sizes: a short token, a typical packet, and a
+/// larger payload.
+const MESSAGE_SIZES: &[usize] = &[64, 1024, 16384];
+
+macro_rules! bench_dilithium {
+    ($c:expr, $dilithium:ty, $group:literal) => {{
+        let dilithium = <$dilithium>::new().expect("native library not available");
+        let mut group = $c.benchmark_group($group);
+
+        group.bench_function("keypair", |b| {
+            b.iter(|| black_box(dilithium.keypair().expect("keypair generation failed")));
+        });
+
+        for &size in MESSAGE_SIZES {
+            let message = vec![0x42u8; size];
+            group.throughput(Throughput::Bytes(size as u64));
+
+            group.bench_with_input(BenchmarkId::new("sign", size), &message, |b, message| {
+                b.iter_batched(
+                    || dilithium.keypair().expect("keypair generation failed").1,
+                    |secret_key| black_box(dilithium.sign(message, &secret_key).expect("signing failed")),
+                    BatchSize::SmallInput,
+                );
+            });
+
+            group.bench_with_input(BenchmarkId::new("verify", size), &message, |b, message| {
+                b.iter_batched(
+                    || {
+                        let (pk, sk) = dilithium.keypair().expect("keypair generation failed");
+                        let sig = dilithium.sign(message, &sk).expect("signing failed");
+                        (sig, pk)
+                    },
+                    |(signature, public_key)| {
+                        black_box(dilithium.verify(message, &signature, &public_key).expect("verification failed"))
+                    },
+                    BatchSize::SmallInput,
+                );
+            });
+        }
+
+        group.finish();
+    }};
+}
+
+fn bench_dilithium2(c: &mut Criterion) {
+    bench_dilithium!(c, Dilithium2, "dilithium2");
+}
+
+fn bench_dilithium3(c: &mut Criterion) {
+    bench_dilithium!(c, Dilithium3, "dilithium3");
+}
+
+fn bench_dilithium5(c: &mut Criterion) {
+    bench_dilithium!(c, Dilithium5, "dilithium5");
+}
+
+criterion_group!(benches, bench_dilithium2, bench_dilithium3, bench_dilithium5);
+criterion_main!(benches);
@@ -0,0 +1,68 @@
+//! Criterion benchmarks for the Kyber KEM operations.
+//!
+//! Modeled on libcrux's `kyber768.rs` bench: keypair generation,
+//! encapsulation and decapsulation are each measured independently so a
+//! regression in one operation doesn't hide in the others' averages.
+//! `BatchSize::SmallInput` forces Criterion to generate a fresh
+//! input (key pair / ciphertext) per measured iteration instead of
+//! reusing one across a batch, since these operations consume their
+//! input. Throughput is reported in bytes of shared secret produced per
+//! second so the three security levels can be compared directly.
+//!
+//! Gated behind the `bench` feature (`cargo bench --features bench`), so
+//! an ordinary `cargo build`/`cargo test` does not build this bench target.
+
+use criterion::{black_box, criterion_group, criterion_main, BatchSize, Criterion, Throughput};
+use pqchub::{Kyber1024, Kyber512, Kyber768};
+
+macro_rules! bench_kyber {
+    ($c:expr, $kyber:ty, $group:literal) => {{
+        let kem = <$kyber>::new().expect("native library not available");
+        let mut group = $c.benchmark_group($group);
+
+        group.throughput(Throughput::Bytes(<$kyber>::SHARED_SECRET_BYTES as u64));
+
+        group.bench_function("keypair", |b| {
+            b.iter(|| black_box(kem.keypair().expect("keypair generation failed")));
+        });
+
+        group.bench_function("encapsulate", |b| {
+            b.iter_batched(
+                || kem.keypair().expect("keypair generation failed").0,
+                |public_key| black_box(kem.encapsulate(&public_key).expect("encapsulation failed")),
+                BatchSize::SmallInput,
+            );
+        });
+
+        group.bench_function("decapsulate", |b| {
+            b.iter_batched(
+                || {
+                    let (pk, sk) = kem.keypair().expect("keypair generation failed");
+                    let (ct, _) = kem.encapsulate(&pk).expect("encapsulation failed");
+                    (ct, sk)
+                },
+                |(ciphertext, secret_key)| {
+                    black_box(kem.decapsulate(&ciphertext, &secret_key).expect("decapsulation failed"))
+                },
+                BatchSize::SmallInput,
+            );
+        });
+
+        group.finish();
+    }};
+}
+
+fn bench_kyber512(c: &mut Criterion) {
+    bench_kyber!(c, Kyber512, "kyber512");
+}
+
+fn bench_kyber768(c: &mut Criterion) {
+    bench_kyber!(c, Kyber768, "kyber768");
+}
+
+fn bench_kyber1024(c: &mut Criterion) {
+    bench_kyber!(c, Kyber1024, "kyber1024");
+}
+
+criterion_group!(benches, bench_kyber512, bench_kyber768, bench_kyber1024);
+criterion_main!(benches);
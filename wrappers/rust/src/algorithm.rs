@@ -0,0 +1,155 @@
+//! Runtime algorithm selection over the compile-time Kyber/Dilithium types.
+//!
+//! [`Algorithm`] names every KEM and signature scheme the crate supports,
+//! mirroring libcrux's `kem::Algorithm`. It lets code that only learns
+//! which algorithm to use at runtime — e.g. a protocol negotiator reading
+//! an identifier off the wire — dispatch into the right FFI call without
+//! a match ladder of its own. [`get_info`](crate::get_info) and
+//! [`crate::get_algorithms`] enumerate their algorithm lists from this
+//! same enum so the JSON metadata can never drift from what dynamic
+//! dispatch actually supports.
+
+use crate::{Dilithium2, Dilithium3, Dilithium5, Kyber1024, Kyber512, Kyber768};
+use crate::{PqcError, PqcResult};
+
+/// A post-quantum algorithm selectable at runtime.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Algorithm {
+    Kyber512,
+    Kyber768,
+    Kyber1024,
+    Dilithium2,
+    Dilithium3,
+    Dilithium5,
+}
+
+impl Algorithm {
+    /// All algorithms this crate supports, in the order used by
+    /// [`crate::get_info`]'s `kem`/`signatures` metadata lists.
+    pub const ALL: &'static [Algorithm] = &[
+        Algorithm::Kyber512,
+        Algorithm::Kyber768,
+        Algorithm::Kyber1024,
+        Algorithm::Dilithium2,
+        Algorithm::Dilithium3,
+        Algorithm::Dilithium5,
+    ];
+
+    /// The algorithm's canonical name, as used by [`Algorithm::from_name`]
+    /// and in the crate's JSON metadata.
+    pub const fn name(self) -> &'static str {
+        match self {
+            Algorithm::Kyber512 => "Kyber512",
+            Algorithm::Kyber768 => "Kyber768",
+            Algorithm::Kyber1024 => "Kyber1024",
+            Algorithm::Dilithium2 => "Dilithium2",
+            Algorithm::Dilithium3 => "Dilithium3",
+            Algorithm::Dilithium5 => "Dilithium5",
+        }
+    }
+
+    /// Look up an algorithm by its [`Algorithm::name`].
+    pub fn from_name(name: &str) -> PqcResult<Self> {
+        Self::ALL
+            .iter()
+            .copied()
+            .find(|alg| alg.name() == name)
+            .ok_or_else(|| PqcError::InvalidAlgorithm(name.to_string()))
+    }
+
+    /// Whether this algorithm is a KEM or a signature scheme.
+    pub const fn is_kem(self) -> bool {
+        matches!(
+            self,
+            Algorithm::Kyber512 | Algorithm::Kyber768 | Algorithm::Kyber1024
+        )
+    }
+}
+
+/// Generate a key pair for `alg`, dispatching to the right FFI call at runtime.
+///
+/// Returns the raw public and secret key bytes. Fails with
+/// [`PqcError::InvalidAlgorithm`] if `alg` is a signature scheme rather
+/// than a KEM.
+pub fn kem_keypair(alg: Algorithm) -> PqcResult<(Vec<u8>, Vec<u8>)> {
+    match alg {
+        Algorithm::Kyber512 => {
+            let (pk, sk) = Kyber512::new()?.keypair()?;
+            Ok((pk.as_bytes().to_vec(), sk.as_bytes().to_vec()))
+        }
+        Algorithm::Kyber768 => {
+            let (pk, sk) = Kyber768::new()?.keypair()?;
+            Ok((pk.as_bytes().to_vec(), sk.as_bytes().to_vec()))
+        }
+        Algorithm::Kyber1024 => {
+            let (pk, sk) = Kyber1024::new()?.keypair()?;
+            Ok((pk.as_bytes().to_vec(), sk.as_bytes().to_vec()))
+        }
+        Algorithm::Dilithium2 | Algorithm::Dilithium3 | Algorithm::Dilithium5 => Err(
+            PqcError::InvalidAlgorithm(format!("{} is not a KEM", alg.name())),
+        ),
+    }
+}
+
+/// Sign `message` with `secret_key` under `alg`, dispatching to the right
+/// FFI call at runtime.
+///
+/// Fails with [`PqcError::InvalidAlgorithm`] if `alg` is a KEM rather
+/// than a signature scheme.
+pub fn sign(alg: Algorithm, message: &[u8], secret_key: &[u8]) -> PqcResult<Vec<u8>> {
+    match alg {
+        Algorithm::Dilithium2 => {
+            let sk = crate::dilithium::Dilithium2SecretKey::from_bytes(secret_key)?;
+            let sig = Dilithium2::new()?.sign(message, &sk)?;
+            Ok(sig.as_bytes().to_vec())
+        }
+        Algorithm::Dilithium3 => {
+            let sk = crate::dilithium::Dilithium3SecretKey::from_bytes(secret_key)?;
+            let sig = Dilithium3::new()?.sign(message, &sk)?;
+            Ok(sig.as_bytes().to_vec())
+        }
+        Algorithm::Dilithium5 => {
+            let sk = crate::dilithium::Dilithium5SecretKey::from_bytes(secret_key)?;
+            let sig = Dilithium5::new()?.sign(message, &sk)?;
+            Ok(sig.as_bytes().to_vec())
+        }
+        Algorithm::Kyber512 | Algorithm::Kyber768 | Algorithm::Kyber1024 => Err(
+            PqcError::InvalidAlgorithm(format!("{} is not a signature scheme", alg.name())),
+        ),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_name_roundtrip() {
+        for alg in Algorithm::ALL {
+            assert_eq!(Algorithm::from_name(alg.name()).unwrap(), *alg);
+        }
+    }
+
+    #[test]
+    fn test_from_name_rejects_unknown() {
+        assert!(Algorithm::from_name("Kyber2048").is_err());
+    }
+
+    #[test]
+    fn test_kem_keypair_dispatch() -> PqcResult<()> {
+        let (pk, sk) = kem_keypair(Algorithm::Kyber512)?;
+        assert_eq!(pk.len(), Kyber512::PUBLIC_KEY_BYTES);
+        assert_eq!(sk.len(), Kyber512::SECRET_KEY_BYTES);
+        Ok(())
+    }
+
+    #[test]
+    fn test_kem_keypair_rejects_signature_algorithm() {
+        assert!(kem_keypair(Algorithm::Dilithium2).is_err());
+    }
+
+    #[test]
+    fn test_sign_rejects_kem_algorithm() {
+        assert!(sign(Algorithm::Kyber512, b"msg", &[]).is_err());
+    }
+}
@@ -0,0 +1,456 @@
+//! Dilithium Digital Signature algorithms
+
+use crate::encoding::{impl_encoding, KeyOid};
+use crate::macros::simple_struct;
+use crate::traits::Signature as SignatureTrait;
+use crate::{PqcError, PqcResult};
+use std::ffi::c_uchar;
+
+/// Dilithium2 Digital Signature algorithm
+///
+/// Security Level: 2
+/// - Public Key: 1312 bytes
+/// - Secret Key: 2528 bytes
+/// - Signature: 2420 bytes
+pub struct Dilithium2 {
+    handle: &'static crate::loader::Handle,
+}
+
+simple_struct!(Dilithium2PublicKey, Dilithium2::PUBLIC_KEY_BYTES);
+impl_encoding!(Dilithium2PublicKey, KeyOid::Dilithium2Public);
+simple_struct!(Dilithium2SecretKey, Dilithium2::SECRET_KEY_BYTES);
+impl_encoding!(Dilithium2SecretKey, KeyOid::Dilithium2Secret);
+simple_struct!(Dilithium2DetachedSignature, Dilithium2::SIGNATURE_BYTES);
+
+impl Dilithium2 {
+    /// Algorithm constants
+    pub const PUBLIC_KEY_BYTES: usize = 1312;
+    pub const SECRET_KEY_BYTES: usize = 2528;
+    pub const SIGNATURE_BYTES: usize = 2420;
+
+    /// Create a new Dilithium2 instance
+    pub fn new() -> PqcResult<Self> {
+        let handle = crate::loader::handle()?;
+        Ok(Self { handle })
+    }
+
+    /// Generate a key pair
+    pub fn keypair(&self) -> PqcResult<(Dilithium2PublicKey, Dilithium2SecretKey)> {
+        let mut public_key = vec![0u8; Self::PUBLIC_KEY_BYTES];
+        let mut secret_key = vec![0u8; Self::SECRET_KEY_BYTES];
+
+        let result = unsafe {
+            (self.handle.dilithium2_keypair)(
+                public_key.as_mut_ptr() as *mut c_uchar,
+                secret_key.as_mut_ptr() as *mut c_uchar,
+            )
+        };
+
+        if result != 0 {
+            return Err(PqcError::KeyGeneration(result));
+        }
+
+        Ok((
+            Dilithium2PublicKey::from_vec_unchecked(public_key),
+            Dilithium2SecretKey::from_vec_unchecked(secret_key),
+        ))
+    }
+
+    /// Sign a message, producing a detached signature
+    pub fn sign(&self, message: &[u8], secret_key: &Dilithium2SecretKey) -> PqcResult<Dilithium2DetachedSignature> {
+        let mut signature = vec![0u8; Self::SIGNATURE_BYTES];
+        let mut signature_len: usize = 0;
+
+        let result = unsafe {
+            (self.handle.dilithium2_sign)(
+                signature.as_mut_ptr() as *mut c_uchar,
+                &mut signature_len,
+                message.as_ptr() as *const c_uchar,
+                message.len(),
+                secret_key.as_bytes().as_ptr() as *const c_uchar,
+            )
+        };
+
+        if result != 0 {
+            return Err(PqcError::Signing(result));
+        }
+
+        signature.truncate(signature_len);
+        signature.resize(Self::SIGNATURE_BYTES, 0);
+        Ok(Dilithium2DetachedSignature::from_vec_unchecked(signature))
+    }
+
+    /// Generate a key pair deterministically from a 48-byte NIST KAT seed.
+    ///
+    /// Seeds the PQClean reference DRBG before calling [`Dilithium2::keypair`],
+    /// so this reproduces a `.rsp` vector's `pk`/`sk` byte-for-byte. See the
+    /// [`crate::kat`] module.
+    pub fn keypair_from_seed(&self, seed: &[u8; 48]) -> PqcResult<(Dilithium2PublicKey, Dilithium2SecretKey)> {
+        crate::seed_drbg(seed)?;
+        self.keypair()
+    }
+
+    /// Sign deterministically from a 48-byte NIST KAT seed.
+    ///
+    /// Seeds the PQClean reference DRBG before calling [`Dilithium2::sign`],
+    /// so this reproduces a `.rsp` vector's signature byte-for-byte. See the
+    /// [`crate::kat`] module.
+    pub fn sign_deterministic(&self, message: &[u8], secret_key: &Dilithium2SecretKey, coins: &[u8; 48]) -> PqcResult<Dilithium2DetachedSignature> {
+        crate::seed_drbg(coins)?;
+        self.sign(message, secret_key)
+    }
+
+    /// Verify a detached signature over a message
+    pub fn verify(&self, message: &[u8], signature: &Dilithium2DetachedSignature, public_key: &Dilithium2PublicKey) -> PqcResult<bool> {
+        let result = unsafe {
+            (self.handle.dilithium2_verify)(
+                signature.as_bytes().as_ptr() as *const c_uchar,
+                signature.as_bytes().len(),
+                message.as_ptr() as *const c_uchar,
+                message.len(),
+                public_key.as_bytes().as_ptr() as *const c_uchar,
+            )
+        };
+
+        Ok(result == 0)
+    }
+}
+
+impl SignatureTrait for Dilithium2 {
+    type PublicKey = Dilithium2PublicKey;
+    type SecretKey = Dilithium2SecretKey;
+    type DetachedSignature = Dilithium2DetachedSignature;
+
+    const PUBLIC_KEY_BYTES: usize = Dilithium2::PUBLIC_KEY_BYTES;
+    const SECRET_KEY_BYTES: usize = Dilithium2::SECRET_KEY_BYTES;
+    const SIGNATURE_BYTES: usize = Dilithium2::SIGNATURE_BYTES;
+
+    fn keypair(&self) -> PqcResult<(Self::PublicKey, Self::SecretKey)> {
+        Dilithium2::keypair(self)
+    }
+
+    fn sign(&self, message: &[u8], secret_key: &Self::SecretKey) -> PqcResult<Self::DetachedSignature> {
+        Dilithium2::sign(self, message, secret_key)
+    }
+
+    fn verify(&self, message: &[u8], signature: &Self::DetachedSignature, public_key: &Self::PublicKey) -> PqcResult<bool> {
+        Dilithium2::verify(self, message, signature, public_key)
+    }
+}
+
+/// Dilithium3 Digital Signature algorithm
+///
+/// Security Level: 3
+/// - Public Key: 1952 bytes
+/// - Secret Key: 4000 bytes
+/// - Signature: 3293 bytes
+pub struct Dilithium3 {
+    handle: &'static crate::loader::Handle,
+}
+
+simple_struct!(Dilithium3PublicKey, Dilithium3::PUBLIC_KEY_BYTES);
+impl_encoding!(Dilithium3PublicKey, KeyOid::Dilithium3Public);
+simple_struct!(Dilithium3SecretKey, Dilithium3::SECRET_KEY_BYTES);
+impl_encoding!(Dilithium3SecretKey, KeyOid::Dilithium3Secret);
+simple_struct!(Dilithium3DetachedSignature, Dilithium3::SIGNATURE_BYTES);
+
+impl Dilithium3 {
+    /// Algorithm constants
+    pub const PUBLIC_KEY_BYTES: usize = 1952;
+    pub const SECRET_KEY_BYTES: usize = 4000;
+    pub const SIGNATURE_BYTES: usize = 3293;
+
+    /// Create a new Dilithium3 instance
+    pub fn new() -> PqcResult<Self> {
+        let handle = crate::loader::handle()?;
+        Ok(Self { handle })
+    }
+
+    /// Generate a key pair
+    pub fn keypair(&self) -> PqcResult<(Dilithium3PublicKey, Dilithium3SecretKey)> {
+        let mut public_key = vec![0u8; Self::PUBLIC_KEY_BYTES];
+        let mut secret_key = vec![0u8; Self::SECRET_KEY_BYTES];
+
+        let result = unsafe {
+            (self.handle.dilithium3_keypair)(
+                public_key.as_mut_ptr() as *mut c_uchar,
+                secret_key.as_mut_ptr() as *mut c_uchar,
+            )
+        };
+
+        if result != 0 {
+            return Err(PqcError::KeyGeneration(result));
+        }
+
+        Ok((
+            Dilithium3PublicKey::from_vec_unchecked(public_key),
+            Dilithium3SecretKey::from_vec_unchecked(secret_key),
+        ))
+    }
+
+    /// Sign a message, producing a detached signature
+    pub fn sign(&self, message: &[u8], secret_key: &Dilithium3SecretKey) -> PqcResult<Dilithium3DetachedSignature> {
+        let mut signature = vec![0u8; Self::SIGNATURE_BYTES];
+        let mut signature_len: usize = 0;
+
+        let result = unsafe {
+            (self.handle.dilithium3_sign)(
+                signature.as_mut_ptr() as *mut c_uchar,
+                &mut signature_len,
+                message.as_ptr() as *const c_uchar,
+                message.len(),
+                secret_key.as_bytes().as_ptr() as *const c_uchar,
+            )
+        };
+
+        if result != 0 {
+            return Err(PqcError::Signing(result));
+        }
+
+        signature.truncate(signature_len);
+        signature.resize(Self::SIGNATURE_BYTES, 0);
+        Ok(Dilithium3DetachedSignature::from_vec_unchecked(signature))
+    }
+
+    /// Generate a key pair deterministically from a 48-byte NIST KAT seed.
+    ///
+    /// Seeds the PQClean reference DRBG before calling [`Dilithium3::keypair`],
+    /// so this reproduces a `.rsp` vector's `pk`/`sk` byte-for-byte. See the
+    /// [`crate::kat`] module.
+    pub fn keypair_from_seed(&self, seed: &[u8; 48]) -> PqcResult<(Dilithium3PublicKey, Dilithium3SecretKey)> {
+        crate::seed_drbg(seed)?;
+        self.keypair()
+    }
+
+    /// Sign deterministically from a 48-byte NIST KAT seed.
+    ///
+    /// Seeds the PQClean reference DRBG before calling [`Dilithium3::sign`],
+    /// so this reproduces a `.rsp` vector's signature byte-for-byte. See the
+    /// [`crate::kat`] module.
+    pub fn sign_deterministic(&self, message: &[u8], secret_key: &Dilithium3SecretKey, coins: &[u8; 48]) -> PqcResult<Dilithium3DetachedSignature> {
+        crate::seed_drbg(coins)?;
+        self.sign(message, secret_key)
+    }
+
+    /// Verify a detached signature over a message
+    pub fn verify(&self, message: &[u8], signature: &Dilithium3DetachedSignature, public_key: &Dilithium3PublicKey) -> PqcResult<bool> {
+        let result = unsafe {
+            (self.handle.dilithium3_verify)(
+                signature.as_bytes().as_ptr() as *const c_uchar,
+                signature.as_bytes().len(),
+                message.as_ptr() as *const c_uchar,
+                message.len(),
+                public_key.as_bytes().as_ptr() as *const c_uchar,
+            )
+        };
+
+        Ok(result == 0)
+    }
+}
+
+impl SignatureTrait for Dilithium3 {
+    type PublicKey = Dilithium3PublicKey;
+    type SecretKey = Dilithium3SecretKey;
+    type DetachedSignature = Dilithium3DetachedSignature;
+
+    const PUBLIC_KEY_BYTES: usize = Dilithium3::PUBLIC_KEY_BYTES;
+    const SECRET_KEY_BYTES: usize = Dilithium3::SECRET_KEY_BYTES;
+    const SIGNATURE_BYTES: usize = Dilithium3::SIGNATURE_BYTES;
+
+    fn keypair(&self) -> PqcResult<(Self::PublicKey, Self::SecretKey)> {
+        Dilithium3::keypair(self)
+    }
+
+    fn sign(&self, message: &[u8], secret_key: &Self::SecretKey) -> PqcResult<Self::DetachedSignature> {
+        Dilithium3::sign(self, message, secret_key)
+    }
+
+    fn verify(&self, message: &[u8], signature: &Self::DetachedSignature, public_key: &Self::PublicKey) -> PqcResult<bool> {
+        Dilithium3::verify(self, message, signature, public_key)
+    }
+}
+
+/// Dilithium5 Digital Signature algorithm
+///
+/// Security Level: 5
+/// - Public Key: 2592 bytes
+/// - Secret Key: 4864 bytes
+/// - Signature: 4595 bytes
+pub struct Dilithium5 {
+    handle: &'static crate::loader::Handle,
+}
+
+simple_struct!(Dilithium5PublicKey, Dilithium5::PUBLIC_KEY_BYTES);
+impl_encoding!(Dilithium5PublicKey, KeyOid::Dilithium5Public);
+simple_struct!(Dilithium5SecretKey, Dilithium5::SECRET_KEY_BYTES);
+impl_encoding!(Dilithium5SecretKey, KeyOid::Dilithium5Secret);
+simple_struct!(Dilithium5DetachedSignature, Dilithium5::SIGNATURE_BYTES);
+
+impl Dilithium5 {
+    /// Algorithm constants
+    pub const PUBLIC_KEY_BYTES: usize = 2592;
+    pub const SECRET_KEY_BYTES: usize = 4864;
+    pub const SIGNATURE_BYTES: usize = 4595;
+
+    /// Create a new Dilithium5 instance
+    pub fn new() -> PqcResult<Self> {
+        let handle = crate::loader::handle()?;
+        Ok(Self { handle })
+    }
+
+    /// Generate a key pair
+    pub fn keypair(&self) -> PqcResult<(Dilithium5PublicKey, Dilithium5SecretKey)> {
+        let mut public_key = vec![0u8; Self::PUBLIC_KEY_BYTES];
+        let mut secret_key = vec![0u8; Self::SECRET_KEY_BYTES];
+
+        let result = unsafe {
+            (self.handle.dilithium5_keypair)(
+                public_key.as_mut_ptr() as *mut c_uchar,
+                secret_key.as_mut_ptr() as *mut c_uchar,
+            )
+        };
+
+        if result != 0 {
+            return Err(PqcError::KeyGeneration(result));
+        }
+
+        Ok((
+            Dilithium5PublicKey::from_vec_unchecked(public_key),
+            Dilithium5SecretKey::from_vec_unchecked(secret_key),
+        ))
+    }
+
+    /// Sign a message, producing a detached signature
+    pub fn sign(&self, message: &[u8], secret_key: &Dilithium5SecretKey) -> PqcResult<Dilithium5DetachedSignature> {
+        let mut signature = vec![0u8; Self::SIGNATURE_BYTES];
+        let mut signature_len: usize = 0;
+
+        let result = unsafe {
+            (self.handle.dilithium5_sign)(
+                signature.as_mut_ptr() as *mut c_uchar,
+                &mut signature_len,
+                message.as_ptr() as *const c_uchar,
+                message.len(),
+                secret_key.as_bytes().as_ptr() as *const c_uchar,
+            )
+        };
+
+        if result != 0 {
+            return Err(PqcError::Signing(result));
+        }
+
+        signature.truncate(signature_len);
+        signature.resize(Self::SIGNATURE_BYTES, 0);
+        Ok(Dilithium5DetachedSignature::from_vec_unchecked(signature))
+    }
+
+    /// Generate a key pair deterministically from a 48-byte NIST KAT seed.
+    ///
+    /// Seeds the PQClean reference DRBG before calling [`Dilithium5::keypair`],
+    /// so this reproduces a `.rsp` vector's `pk`/`sk` byte-for-byte. See the
+    /// [`crate::kat`] module.
+    pub fn keypair_from_seed(&self, seed: &[u8; 48]) -> PqcResult<(Dilithium5PublicKey, Dilithium5SecretKey)> {
+        crate::seed_drbg(seed)?;
+        self.keypair()
+    }
+
+    /// Sign deterministically from a 48-byte NIST KAT seed.
+    ///
+    /// Seeds the PQClean reference DRBG before calling [`Dilithium5::sign`],
+    /// so this reproduces a `.rsp` vector's signature byte-for-byte. See the
+    /// [`crate::kat`] module.
+    pub fn sign_deterministic(&self, message: &[u8], secret_key: &Dilithium5SecretKey, coins: &[u8; 48]) -> PqcResult<Dilithium5DetachedSignature> {
+        crate::seed_drbg(coins)?;
+        self.sign(message, secret_key)
+    }
+
+    /// Verify a detached signature over a message
+    pub fn verify(&self, message: &[u8], signature: &Dilithium5DetachedSignature, public_key: &Dilithium5PublicKey) -> PqcResult<bool> {
+        let result = unsafe {
+            (self.handle.dilithium5_verify)(
+                signature.as_bytes().as_ptr() as *const c_uchar,
+                signature.as_bytes().len(),
+                message.as_ptr() as *const c_uchar,
+                message.len(),
+                public_key.as_bytes().as_ptr() as *const c_uchar,
+            )
+        };
+
+        Ok(result == 0)
+    }
+}
+
+impl SignatureTrait for Dilithium5 {
+    type PublicKey = Dilithium5PublicKey;
+    type SecretKey = Dilithium5SecretKey;
+    type DetachedSignature = Dilithium5DetachedSignature;
+
+    const PUBLIC_KEY_BYTES: usize = Dilithium5::PUBLIC_KEY_BYTES;
+    const SECRET_KEY_BYTES: usize = Dilithium5::SECRET_KEY_BYTES;
+    const SIGNATURE_BYTES: usize = Dilithium5::SIGNATURE_BYTES;
+
+    fn keypair(&self) -> PqcResult<(Self::PublicKey, Self::SecretKey)> {
+        Dilithium5::keypair(self)
+    }
+
+    fn sign(&self, message: &[u8], secret_key: &Self::SecretKey) -> PqcResult<Self::DetachedSignature> {
+        Dilithium5::sign(self, message, secret_key)
+    }
+
+    fn verify(&self, message: &[u8], signature: &Self::DetachedSignature, public_key: &Self::PublicKey) -> PqcResult<bool> {
+        Dilithium5::verify(self, message, signature, public_key)
+    }
+}
+
+/// Type alias for the default Dilithium variant (Dilithium3)
+pub type Dilithium = Dilithium3;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_dilithium2() -> PqcResult<()> {
+        let dilithium = Dilithium2::new()?;
+        let (pk, sk) = dilithium.keypair()?;
+
+        assert_eq!(pk.as_bytes().len(), Dilithium2::PUBLIC_KEY_BYTES);
+        assert_eq!(sk.as_bytes().len(), Dilithium2::SECRET_KEY_BYTES);
+
+        let message = b"Hello, post-quantum world!";
+        let signature = dilithium.sign(message, &sk)?;
+        assert!(dilithium.verify(message, &signature, &pk)?);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_dilithium3() -> PqcResult<()> {
+        let dilithium = Dilithium3::new()?;
+        let (pk, sk) = dilithium.keypair()?;
+
+        assert_eq!(pk.as_bytes().len(), Dilithium3::PUBLIC_KEY_BYTES);
+        assert_eq!(sk.as_bytes().len(), Dilithium3::SECRET_KEY_BYTES);
+
+        let message = b"Hello, post-quantum world!";
+        let signature = dilithium.sign(message, &sk)?;
+        assert!(dilithium.verify(message, &signature, &pk)?);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_dilithium5() -> PqcResult<()> {
+        let dilithium = Dilithium5::new()?;
+        let (pk, sk) = dilithium.keypair()?;
+
+        assert_eq!(pk.as_bytes().len(), Dilithium5::PUBLIC_KEY_BYTES);
+        assert_eq!(sk.as_bytes().len(), Dilithium5::SECRET_KEY_BYTES);
+
+        let message = b"Hello, post-quantum world!";
+        let signature = dilithium.sign(message, &sk)?;
+        assert!(dilithium.verify(message, &signature, &pk)?);
+
+        Ok(())
+    }
+}
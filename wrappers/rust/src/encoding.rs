@@ -0,0 +1,347 @@
+//! DER/PEM encoding and file persistence for typed keys.
+//!
+//! Each key is wrapped in a minimal SubjectPublicKeyInfo-style DER
+//! structure: a SEQUENCE of an algorithm OID identifying the concrete
+//! key type, followed by an OCTET STRING of the raw key bytes.
+//! [`to_pem`]/[`from_pem`] base64-wrap that DER under
+//! `-----BEGIN PQC ... KEY-----` style labels, following the key-storage
+//! conventions in openssl's `pkey`. On load, the algorithm OID is
+//! checked against the caller's expected type so, for example, a
+//! Kyber768 key can never be loaded as a Kyber512 key.
+
+use crate::{PqcError, PqcResult};
+use base64::{engine::general_purpose::STANDARD, Engine as _};
+
+/// Identifies which concrete key type a DER/PEM blob decodes to.
+///
+/// These OIDs live under a private arc (`1.3.6.1.4.1.99999.1.*`) and are
+/// only meaningful within this crate; they exist so loading can refuse a
+/// length-and-shape match between the wrong algorithm and key kind, not
+/// to interoperate with other PKI tooling.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum KeyOid {
+    Kyber512Public,
+    Kyber512Secret,
+    Kyber768Public,
+    Kyber768Secret,
+    Kyber1024Public,
+    Kyber1024Secret,
+    Dilithium2Public,
+    Dilithium2Secret,
+    Dilithium3Public,
+    Dilithium3Secret,
+    Dilithium5Public,
+    Dilithium5Secret,
+}
+
+impl KeyOid {
+    const fn oid(self) -> &'static str {
+        match self {
+            KeyOid::Kyber512Public => "1.3.6.1.4.1.99999.1.1",
+            KeyOid::Kyber512Secret => "1.3.6.1.4.1.99999.1.2",
+            KeyOid::Kyber768Public => "1.3.6.1.4.1.99999.1.3",
+            KeyOid::Kyber768Secret => "1.3.6.1.4.1.99999.1.4",
+            KeyOid::Kyber1024Public => "1.3.6.1.4.1.99999.1.5",
+            KeyOid::Kyber1024Secret => "1.3.6.1.4.1.99999.1.6",
+            KeyOid::Dilithium2Public => "1.3.6.1.4.1.99999.1.7",
+            KeyOid::Dilithium2Secret => "1.3.6.1.4.1.99999.1.8",
+            KeyOid::Dilithium3Public => "1.3.6.1.4.1.99999.1.9",
+            KeyOid::Dilithium3Secret => "1.3.6.1.4.1.99999.1.10",
+            KeyOid::Dilithium5Public => "1.3.6.1.4.1.99999.1.11",
+            KeyOid::Dilithium5Secret => "1.3.6.1.4.1.99999.1.12",
+        }
+    }
+
+    fn from_oid(oid: &str) -> Option<Self> {
+        Some(match oid {
+            "1.3.6.1.4.1.99999.1.1" => KeyOid::Kyber512Public,
+            "1.3.6.1.4.1.99999.1.2" => KeyOid::Kyber512Secret,
+            "1.3.6.1.4.1.99999.1.3" => KeyOid::Kyber768Public,
+            "1.3.6.1.4.1.99999.1.4" => KeyOid::Kyber768Secret,
+            "1.3.6.1.4.1.99999.1.5" => KeyOid::Kyber1024Public,
+            "1.3.6.1.4.1.99999.1.6" => KeyOid::Kyber1024Secret,
+            "1.3.6.1.4.1.99999.1.7" => KeyOid::Dilithium2Public,
+            "1.3.6.1.4.1.99999.1.8" => KeyOid::Dilithium2Secret,
+            "1.3.6.1.4.1.99999.1.9" => KeyOid::Dilithium3Public,
+            "1.3.6.1.4.1.99999.1.10" => KeyOid::Dilithium3Secret,
+            "1.3.6.1.4.1.99999.1.11" => KeyOid::Dilithium5Public,
+            "1.3.6.1.4.1.99999.1.12" => KeyOid::Dilithium5Secret,
+            _ => return None,
+        })
+    }
+
+    /// PEM label, e.g. `"KYBER512 PUBLIC"`.
+    const fn label(self) -> &'static str {
+        match self {
+            KeyOid::Kyber512Public => "KYBER512 PUBLIC",
+            KeyOid::Kyber512Secret => "KYBER512 SECRET",
+            KeyOid::Kyber768Public => "KYBER768 PUBLIC",
+            KeyOid::Kyber768Secret => "KYBER768 SECRET",
+            KeyOid::Kyber1024Public => "KYBER1024 PUBLIC",
+            KeyOid::Kyber1024Secret => "KYBER1024 SECRET",
+            KeyOid::Dilithium2Public => "DILITHIUM2 PUBLIC",
+            KeyOid::Dilithium2Secret => "DILITHIUM2 SECRET",
+            KeyOid::Dilithium3Public => "DILITHIUM3 PUBLIC",
+            KeyOid::Dilithium3Secret => "DILITHIUM3 SECRET",
+            KeyOid::Dilithium5Public => "DILITHIUM5 PUBLIC",
+            KeyOid::Dilithium5Secret => "DILITHIUM5 SECRET",
+        }
+    }
+}
+
+fn push_der_length(len: usize, out: &mut Vec<u8>) {
+    if len < 0x80 {
+        out.push(len as u8);
+        return;
+    }
+    let be = len.to_be_bytes();
+    let first_nonzero = be.iter().position(|&b| b != 0).unwrap_or(be.len() - 1);
+    let significant = &be[first_nonzero..];
+    out.push(0x80 | significant.len() as u8);
+    out.extend_from_slice(significant);
+}
+
+fn push_base128(mut value: u64, out: &mut Vec<u8>) {
+    let mut groups = vec![(value & 0x7f) as u8];
+    value >>= 7;
+    while value > 0 {
+        groups.push(((value & 0x7f) as u8) | 0x80);
+        value >>= 7;
+    }
+    groups.reverse();
+    out.extend_from_slice(&groups);
+}
+
+fn encode_oid_body(oid: &str) -> PqcResult<Vec<u8>> {
+    let parts: Vec<u64> = oid
+        .split('.')
+        .map(|p| p.parse::<u64>().map_err(|e| PqcError::Encoding(e.to_string())))
+        .collect::<PqcResult<_>>()?;
+    if parts.len() < 2 {
+        return Err(PqcError::Encoding("OID must have at least two arcs".to_string()));
+    }
+
+    let mut body = Vec::new();
+    push_base128(parts[0] * 40 + parts[1], &mut body);
+    for &arc in &parts[2..] {
+        push_base128(arc, &mut body);
+    }
+    Ok(body)
+}
+
+fn decode_oid_body(body: &[u8]) -> PqcResult<String> {
+    let mut arcs = Vec::new();
+    let mut value: u64 = 0;
+    for &byte in body {
+        value = (value << 7) | (byte & 0x7f) as u64;
+        if byte & 0x80 == 0 {
+            arcs.push(value);
+            value = 0;
+        }
+    }
+    if arcs.is_empty() {
+        return Err(PqcError::Encoding("empty OID".to_string()));
+    }
+
+    let first = arcs[0] / 40;
+    let second = arcs[0] % 40;
+    let mut dotted = format!("{first}.{second}");
+    for arc in &arcs[1..] {
+        dotted.push('.');
+        dotted.push_str(&arc.to_string());
+    }
+    Ok(dotted)
+}
+
+/// Read one tag-length-value triple starting at `*pos`, returning `(tag, value)`.
+fn read_tlv<'a>(buf: &'a [u8], pos: &mut usize) -> PqcResult<(u8, &'a [u8])> {
+    let too_short = || PqcError::Encoding("truncated DER".to_string());
+
+    let tag = *buf.get(*pos).ok_or_else(too_short)?;
+    *pos += 1;
+    let len_byte = *buf.get(*pos).ok_or_else(too_short)?;
+    *pos += 1;
+
+    let len = if len_byte & 0x80 == 0 {
+        len_byte as usize
+    } else {
+        let count = (len_byte & 0x7f) as usize;
+        if count > std::mem::size_of::<usize>() {
+            return Err(PqcError::Encoding("DER length too large".to_string()));
+        }
+        let end = pos.checked_add(count).ok_or_else(too_short)?;
+        let bytes = buf.get(*pos..end).ok_or_else(too_short)?;
+        *pos = end;
+        bytes.iter().fold(0usize, |acc, &b| (acc << 8) | b as usize)
+    };
+
+    let end = pos.checked_add(len).ok_or_else(too_short)?;
+    let value = buf.get(*pos..end).ok_or_else(too_short)?;
+    *pos = end;
+    Ok((tag, value))
+}
+
+const TAG_SEQUENCE: u8 = 0x30;
+const TAG_OID: u8 = 0x06;
+const TAG_OCTET_STRING: u8 = 0x04;
+
+/// DER-encode a key as `SEQUENCE { OBJECT IDENTIFIER, OCTET STRING }`.
+pub(crate) fn to_der(oid: KeyOid, key_bytes: &[u8]) -> PqcResult<Vec<u8>> {
+    let oid_body = encode_oid_body(oid.oid())?;
+    let mut oid_tlv = vec![TAG_OID];
+    push_der_length(oid_body.len(), &mut oid_tlv);
+    oid_tlv.extend_from_slice(&oid_body);
+
+    let mut octet_tlv = vec![TAG_OCTET_STRING];
+    push_der_length(key_bytes.len(), &mut octet_tlv);
+    octet_tlv.extend_from_slice(key_bytes);
+
+    let mut body = oid_tlv;
+    body.extend_from_slice(&octet_tlv);
+
+    let mut seq = vec![TAG_SEQUENCE];
+    push_der_length(body.len(), &mut seq);
+    seq.extend_from_slice(&body);
+    Ok(seq)
+}
+
+/// Decode a DER blob produced by [`to_der`], returning the algorithm OID and raw key bytes.
+pub(crate) fn from_der(der: &[u8]) -> PqcResult<(KeyOid, Vec<u8>)> {
+    let mut pos = 0;
+    let (tag, body) = read_tlv(der, &mut pos)?;
+    if tag != TAG_SEQUENCE {
+        return Err(PqcError::Encoding(format!("expected SEQUENCE, got tag {tag:#x}")));
+    }
+
+    let mut inner_pos = 0;
+    let (oid_tag, oid_body) = read_tlv(body, &mut inner_pos)?;
+    if oid_tag != TAG_OID {
+        return Err(PqcError::Encoding(format!("expected OBJECT IDENTIFIER, got tag {oid_tag:#x}")));
+    }
+    let oid_str = decode_oid_body(oid_body)?;
+    let oid = KeyOid::from_oid(&oid_str).ok_or_else(|| PqcError::Encoding(format!("unknown algorithm OID {oid_str}")))?;
+
+    let (octet_tag, octet_body) = read_tlv(body, &mut inner_pos)?;
+    if octet_tag != TAG_OCTET_STRING {
+        return Err(PqcError::Encoding(format!("expected OCTET STRING, got tag {octet_tag:#x}")));
+    }
+
+    Ok((oid, octet_body.to_vec()))
+}
+
+/// PEM-encode a key: base64-wrap its [`to_der`] form under a `-----BEGIN PQC ... KEY-----` label.
+pub(crate) fn to_pem(oid: KeyOid, key_bytes: &[u8]) -> PqcResult<String> {
+    let der = to_der(oid, key_bytes)?;
+    let b64 = STANDARD.encode(der);
+
+    let label = oid.label();
+    let mut pem = format!("-----BEGIN PQC {label} KEY-----\n");
+    for line in b64.as_bytes().chunks(64) {
+        pem.push_str(std::str::from_utf8(line).expect("base64 output is ASCII"));
+        pem.push('\n');
+    }
+    pem.push_str(&format!("-----END PQC {label} KEY-----\n"));
+    Ok(pem)
+}
+
+/// Decode a PEM blob produced by [`to_pem`], returning the algorithm OID and raw key bytes.
+pub(crate) fn from_pem(pem: &str) -> PqcResult<(KeyOid, Vec<u8>)> {
+    let body: String = pem
+        .lines()
+        .filter(|line| !line.starts_with("-----"))
+        .collect();
+    let der = STANDARD
+        .decode(body.trim())
+        .map_err(|e| PqcError::Encoding(format!("invalid PEM base64: {e}")))?;
+    from_der(&der)
+}
+
+/// Implement `to_der`/`from_der`/`to_pem`/`from_pem`/`save_to_file`/`load_from_file`
+/// on a typed key newtype generated by [`crate::macros::simple_struct`].
+macro_rules! impl_encoding {
+    ($name:ident, $oid:expr) => {
+        impl $name {
+            /// Encode this key as a minimal SubjectPublicKeyInfo-style DER blob.
+            pub fn to_der(&self) -> PqcResult<Vec<u8>> {
+                crate::encoding::to_der($oid, self.as_bytes())
+            }
+
+            /// Decode a DER blob produced by [`Self::to_der`].
+            ///
+            /// Fails if the embedded algorithm OID does not match this key type.
+            pub fn from_der(der: &[u8]) -> PqcResult<Self> {
+                let (oid, bytes) = crate::encoding::from_der(der)?;
+                if oid != $oid {
+                    return Err(PqcError::Encoding(format!(
+                        "DER algorithm OID does not match {}",
+                        stringify!($name)
+                    )));
+                }
+                Self::from_bytes(&bytes)
+            }
+
+            /// Encode this key as PEM.
+            pub fn to_pem(&self) -> PqcResult<String> {
+                crate::encoding::to_pem($oid, self.as_bytes())
+            }
+
+            /// Decode a PEM blob produced by [`Self::to_pem`].
+            ///
+            /// Fails if the embedded algorithm OID does not match this key type.
+            pub fn from_pem(pem: &str) -> PqcResult<Self> {
+                let (oid, bytes) = crate::encoding::from_pem(pem)?;
+                if oid != $oid {
+                    return Err(PqcError::Encoding(format!(
+                        "PEM algorithm OID does not match {}",
+                        stringify!($name)
+                    )));
+                }
+                Self::from_bytes(&bytes)
+            }
+
+            /// Save this key to `path` as PEM.
+            pub fn save_to_file(&self, path: impl AsRef<std::path::Path>) -> PqcResult<()> {
+                std::fs::write(path, self.to_pem()?)?;
+                Ok(())
+            }
+
+            /// Load a key previously written by [`Self::save_to_file`].
+            pub fn load_from_file(path: impl AsRef<std::path::Path>) -> PqcResult<Self> {
+                let pem = std::fs::read_to_string(path)?;
+                Self::from_pem(&pem)
+            }
+        }
+    };
+}
+
+pub(crate) use impl_encoding;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_der_roundtrip() {
+        let key_bytes = vec![1u8, 2, 3, 4, 5];
+        let der = to_der(KeyOid::Kyber512Public, &key_bytes).unwrap();
+        let (oid, bytes) = from_der(&der).unwrap();
+        assert_eq!(oid, KeyOid::Kyber512Public);
+        assert_eq!(bytes, key_bytes);
+    }
+
+    #[test]
+    fn test_read_tlv_rejects_oversized_long_form_length_without_panicking() {
+        // Tag SEQUENCE, long-form length with 8 count bytes all 0xFF:
+        // len would overflow `*pos + len` as a plain `usize` addition.
+        let der = [0x30u8, 0x88, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF];
+        assert!(from_der(&der).is_err());
+    }
+
+    #[test]
+    fn test_read_tlv_rejects_length_count_wider_than_usize() {
+        // 9 count bytes on a 64-bit usize: the bound check must reject
+        // this before folding, not just avoid panicking while folding it.
+        let der = [0x30u8, 0x89, 0, 0, 0, 0, 0, 0, 0, 0, 1];
+        assert!(from_der(&der).is_err());
+    }
+}
@@ -0,0 +1,132 @@
+//! Hybrid KEM-DEM authenticated public-key encryption.
+//!
+//! A raw KEM only gives you a shared secret; this module turns that into
+//! actual authenticated encryption of arbitrary plaintext, mirroring the
+//! Kyber+AEAD combination used by crypt_guard: `encrypt` runs
+//! `Kyber{512,768,1024}::encapsulate` to get a `(kem_ciphertext,
+//! shared_secret)` pair, derives a 256-bit key from the shared secret
+//! with SHA3-256, and uses that key to AEAD-encrypt the plaintext under
+//! a random nonce.
+//!
+//! The result is a self-describing envelope:
+//!
+//! ```text
+//! kem_ciphertext || nonce || aead_ciphertext_and_tag
+//! ```
+//!
+//! so `decrypt` only needs the KEM's `CIPHERTEXT_BYTES` constant to know
+//! where the envelope splits; everything after that is handed to the
+//! AEAD as-is.
+
+#[cfg(not(any(feature = "aes", feature = "xchacha")))]
+compile_error!("the `hybrid` module requires the `aes` or `xchacha` feature to be enabled");
+
+use crate::kyber::{
+    Kyber1024, Kyber1024Ciphertext, Kyber1024PublicKey, Kyber1024SecretKey, Kyber512, Kyber512Ciphertext,
+    Kyber512PublicKey, Kyber512SecretKey, Kyber768, Kyber768Ciphertext, Kyber768PublicKey, Kyber768SecretKey,
+};
+use crate::{PqcError, PqcResult};
+use sha3::{Digest, Sha3_256};
+
+#[cfg(feature = "aes")]
+use aes_gcm::{
+    aead::{Aead, AeadCore, KeyInit, OsRng},
+    Aes256Gcm, Nonce,
+};
+#[cfg(all(feature = "xchacha", not(feature = "aes")))]
+use chacha20poly1305::{
+    aead::{Aead, AeadCore, KeyInit, OsRng},
+    XChaCha20Poly1305, XNonce,
+};
+
+/// Length of the AEAD nonce prepended to the envelope.
+#[cfg(feature = "aes")]
+const NONCE_BYTES: usize = 12;
+#[cfg(all(feature = "xchacha", not(feature = "aes")))]
+const NONCE_BYTES: usize = 24;
+
+/// Derive a 32-byte symmetric key from a KEM shared secret via SHA3-256.
+fn derive_key(shared_secret: &[u8]) -> [u8; 32] {
+    let mut hasher = Sha3_256::new();
+    hasher.update(shared_secret);
+    hasher.finalize().into()
+}
+
+/// AEAD-seal `plaintext` under a key derived from `shared_secret`, returning `nonce || ciphertext`.
+fn seal(shared_secret: &[u8], plaintext: &[u8]) -> PqcResult<Vec<u8>> {
+    let key = derive_key(shared_secret);
+
+    #[cfg(feature = "aes")]
+    let (nonce, aead_ciphertext) = {
+        let cipher = Aes256Gcm::new_from_slice(&key).map_err(|e| PqcError::Aead(e.to_string()))?;
+        let nonce = Aes256Gcm::generate_nonce(&mut OsRng);
+        let aead_ciphertext = cipher.encrypt(&nonce, plaintext).map_err(|e| PqcError::Aead(e.to_string()))?;
+        (nonce.to_vec(), aead_ciphertext)
+    };
+
+    #[cfg(all(feature = "xchacha", not(feature = "aes")))]
+    let (nonce, aead_ciphertext) = {
+        let cipher = XChaCha20Poly1305::new_from_slice(&key).map_err(|e| PqcError::Aead(e.to_string()))?;
+        let nonce = XChaCha20Poly1305::generate_nonce(&mut OsRng);
+        let aead_ciphertext = cipher.encrypt(&nonce, plaintext).map_err(|e| PqcError::Aead(e.to_string()))?;
+        (nonce.to_vec(), aead_ciphertext)
+    };
+
+    let mut sealed = Vec::with_capacity(nonce.len() + aead_ciphertext.len());
+    sealed.extend_from_slice(&nonce);
+    sealed.extend_from_slice(&aead_ciphertext);
+    Ok(sealed)
+}
+
+/// AEAD-open a `nonce || ciphertext` blob under a key derived from `shared_secret`.
+fn open(shared_secret: &[u8], sealed: &[u8]) -> PqcResult<Vec<u8>> {
+    if sealed.len() < NONCE_BYTES {
+        return Err(PqcError::Aead("envelope too short".to_string()));
+    }
+    let key = derive_key(shared_secret);
+    let (nonce_bytes, aead_ciphertext) = sealed.split_at(NONCE_BYTES);
+
+    #[cfg(feature = "aes")]
+    {
+        let cipher = Aes256Gcm::new_from_slice(&key).map_err(|e| PqcError::Aead(e.to_string()))?;
+        let nonce = Nonce::from_slice(nonce_bytes);
+        cipher.decrypt(nonce, aead_ciphertext).map_err(|e| PqcError::Aead(e.to_string()))
+    }
+
+    #[cfg(all(feature = "xchacha", not(feature = "aes")))]
+    {
+        let cipher = XChaCha20Poly1305::new_from_slice(&key).map_err(|e| PqcError::Aead(e.to_string()))?;
+        let nonce = XNonce::from_slice(nonce_bytes);
+        cipher.decrypt(nonce, aead_ciphertext).map_err(|e| PqcError::Aead(e.to_string()))
+    }
+}
+
+macro_rules! hybrid_impl {
+    ($encrypt:ident, $decrypt:ident, $kem:ty, $pk:ty, $sk:ty, $ct:ty) => {
+        #[doc = concat!("Hybrid-encrypt `plaintext` under a ", stringify!($kem), " public key.")]
+        pub fn $encrypt(kem: &$kem, public_key: &$pk, plaintext: &[u8]) -> PqcResult<Vec<u8>> {
+            let (kem_ciphertext, shared_secret) = kem.encapsulate(public_key)?;
+            let sealed = seal(shared_secret.as_bytes(), plaintext)?;
+
+            let mut envelope = Vec::with_capacity(kem_ciphertext.as_bytes().len() + sealed.len());
+            envelope.extend_from_slice(kem_ciphertext.as_bytes());
+            envelope.extend_from_slice(&sealed);
+            Ok(envelope)
+        }
+
+        #[doc = concat!("Decrypt an envelope produced by [`", stringify!($encrypt), "`].")]
+        pub fn $decrypt(kem: &$kem, secret_key: &$sk, envelope: &[u8]) -> PqcResult<Vec<u8>> {
+            if envelope.len() < <$ct>::BYTES {
+                return Err(PqcError::Aead("envelope too short".to_string()));
+            }
+            let (kem_ciphertext_bytes, sealed) = envelope.split_at(<$ct>::BYTES);
+            let kem_ciphertext = <$ct>::from_bytes(kem_ciphertext_bytes)?;
+            let shared_secret = kem.decapsulate(&kem_ciphertext, secret_key)?;
+            open(shared_secret.as_bytes(), sealed)
+        }
+    };
+}
+
+hybrid_impl!(encrypt_kyber512, decrypt_kyber512, Kyber512, Kyber512PublicKey, Kyber512SecretKey, Kyber512Ciphertext);
+hybrid_impl!(encrypt_kyber768, decrypt_kyber768, Kyber768, Kyber768PublicKey, Kyber768SecretKey, Kyber768Ciphertext);
+hybrid_impl!(encrypt_kyber1024, decrypt_kyber1024, Kyber1024, Kyber1024PublicKey, Kyber1024SecretKey, Kyber1024Ciphertext);
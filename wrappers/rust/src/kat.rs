@@ -0,0 +1,124 @@
+//! NIST Known-Answer-Test (KAT) vector support.
+//!
+//! PQClean's reference implementations draw randomness from a
+//! replaceable `randombytes()` symbol backed by an AES-256-CTR DRBG, the
+//! same generator NIST's submission tooling uses to produce `.rsp`
+//! vector files. Seeding that DRBG once per vector with
+//! [`crate::seed_drbg`] (via `*_from_seed` on each algorithm type), then
+//! letting keygen and encapsulation draw from that same continuing
+//! stream with plain `keypair`/`encapsulate` calls, reproduces the
+//! vector's `pk`/`sk`/`ct`/`ss` byte-for-byte — reseeding between the
+//! two calls would reset the stream and desync the outputs. This module
+//! only needs to parse the `.rsp` format and let the test harness
+//! compare.
+
+use std::path::Path;
+
+/// One `count = N` record parsed out of a NIST `.rsp` KAT file.
+#[derive(Debug, Clone, Default)]
+pub struct KatVector {
+    pub count: u32,
+    pub seed: Vec<u8>,
+    pub pk: Vec<u8>,
+    pub sk: Vec<u8>,
+    pub ct: Vec<u8>,
+    pub ss: Vec<u8>,
+}
+
+fn hex_decode(s: &str) -> Vec<u8> {
+    (0..s.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(&s[i..i + 2], 16).expect("invalid hex in KAT vector"))
+        .collect()
+}
+
+/// Parse a KEM `.rsp` file's contents (`count`/`seed`/`pk`/`sk`/`ct`/`ss` lines) into vectors.
+pub fn parse_kem_rsp(contents: &str) -> Vec<KatVector> {
+    let mut vectors = Vec::new();
+    let mut current = KatVector::default();
+
+    for line in contents.lines() {
+        let Some((key, value)) = line.trim().split_once('=') else {
+            continue;
+        };
+        let value = value.trim();
+        match key.trim() {
+            "count" => current.count = value.parse().unwrap_or(0),
+            "seed" => current.seed = hex_decode(value),
+            "pk" => current.pk = hex_decode(value),
+            "sk" => current.sk = hex_decode(value),
+            "ct" => current.ct = hex_decode(value),
+            "ss" => {
+                current.ss = hex_decode(value);
+                vectors.push(std::mem::take(&mut current));
+            }
+            _ => {}
+        }
+    }
+
+    vectors
+}
+
+/// Load and parse a KEM `.rsp` file from disk.
+pub fn load_kem_rsp(path: impl AsRef<Path>) -> std::io::Result<Vec<KatVector>> {
+    let contents = std::fs::read_to_string(path)?;
+    Ok(parse_kem_rsp(&contents))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::kyber::{Kyber1024, Kyber512, Kyber768};
+
+    /// Directory holding the official PQClean/NIST `.rsp` vectors, when present.
+    ///
+    /// These files are large and not checked into this repository; drop
+    /// them in locally to exercise this test.
+    fn kat_dir() -> std::path::PathBuf {
+        Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/kat")
+    }
+
+    macro_rules! kem_kat_test {
+        ($name:ident, $file:expr, $kem:ty) => {
+            #[test]
+            fn $name() {
+                let path = kat_dir().join($file);
+                let vectors = match load_kem_rsp(&path) {
+                    Ok(v) => v,
+                    Err(_) => {
+                        println!("no KAT vectors at {}, skipping", path.display());
+                        return;
+                    }
+                };
+
+                let kem = <$kem>::new().expect("library not available");
+                for vector in vectors {
+                    let seed: [u8; 48] = vector.seed.as_slice().try_into().expect("KAT seed must be 48 bytes");
+
+                    let (pk, sk) = kem.keypair_from_seed(&seed).expect("deterministic keypair");
+                    assert_eq!(pk.as_bytes(), vector.pk.as_slice(), "pk mismatch at count {}", vector.count);
+                    assert_eq!(sk.as_bytes(), vector.sk.as_slice(), "sk mismatch at count {}", vector.count);
+
+                    let (ct, ss) = kem.encapsulate(&pk).expect("encapsulate");
+                    assert_eq!(ct.as_bytes(), vector.ct.as_slice(), "ct mismatch at count {}", vector.count);
+                    assert_eq!(ss.as_bytes(), vector.ss.as_slice(), "ss mismatch at count {}", vector.count);
+                }
+            }
+        };
+    }
+
+    kem_kat_test!(test_kyber512_kat_vectors, "kyber512.rsp", Kyber512);
+    kem_kat_test!(test_kyber768_kat_vectors, "kyber768.rsp", Kyber768);
+    kem_kat_test!(test_kyber1024_kat_vectors, "kyber1024.rsp", Kyber1024);
+
+    #[test]
+    fn test_parse_kem_rsp() {
+        let sample = "count = 0\nseed = AABB\npk = CCDD\nsk = EEFF\nct = 0011\nss = 2233\n\ncount = 1\nseed = 00\npk = 01\nsk = 02\nct = 03\nss = 04\n";
+        let vectors = parse_kem_rsp(sample);
+        assert_eq!(vectors.len(), 2);
+        assert_eq!(vectors[0].count, 0);
+        assert_eq!(vectors[0].seed, vec![0xAA, 0xBB]);
+        assert_eq!(vectors[0].ss, vec![0x22, 0x33]);
+        assert_eq!(vectors[1].count, 1);
+    }
+}
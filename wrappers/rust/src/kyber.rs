@@ -1,19 +1,29 @@
 //! Kyber Key Encapsulation Mechanisms
 
-use crate::{PqcError, PqcResult, validate_length};
+use crate::encoding::{impl_encoding, KeyOid};
+use crate::macros::simple_struct;
+use crate::traits::Kem;
+use crate::{PqcError, PqcResult};
 use std::ffi::c_uchar;
 
 /// Kyber512 Key Encapsulation Mechanism
-/// 
+///
 /// Security Level: 1 (equivalent to AES-128)
 /// - Public Key: 800 bytes
-/// - Secret Key: 1632 bytes  
+/// - Secret Key: 1632 bytes
 /// - Ciphertext: 768 bytes
 /// - Shared Secret: 32 bytes
 pub struct Kyber512 {
-    _private: (),
+    handle: &'static crate::loader::Handle,
 }
 
+simple_struct!(Kyber512PublicKey, Kyber512::PUBLIC_KEY_BYTES);
+impl_encoding!(Kyber512PublicKey, KeyOid::Kyber512Public);
+simple_struct!(Kyber512SecretKey, Kyber512::SECRET_KEY_BYTES);
+impl_encoding!(Kyber512SecretKey, KeyOid::Kyber512Secret);
+simple_struct!(Kyber512Ciphertext, Kyber512::CIPHERTEXT_BYTES);
+simple_struct!(Kyber512SharedSecret, Kyber512::SHARED_SECRET_BYTES);
+
 impl Kyber512 {
     /// Algorithm constants
     pub const PUBLIC_KEY_BYTES: usize = 800;
@@ -23,18 +33,17 @@ impl Kyber512 {
 
     /// Create a new Kyber512 instance
     pub fn new() -> PqcResult<Self> {
-        // Verify library is available
-        crate::find_binary_path()?;
-        Ok(Self { _private: () })
+        let handle = crate::loader::handle()?;
+        Ok(Self { handle })
     }
 
     /// Generate a key pair
-    pub fn keypair(&self) -> PqcResult<(Vec<u8>, Vec<u8>)> {
+    pub fn keypair(&self) -> PqcResult<(Kyber512PublicKey, Kyber512SecretKey)> {
         let mut public_key = vec![0u8; Self::PUBLIC_KEY_BYTES];
         let mut secret_key = vec![0u8; Self::SECRET_KEY_BYTES];
 
         let result = unsafe {
-            crate::PQCLEAN_KYBER512_CLEAN_crypto_kem_keypair(
+            (self.handle.kyber512_keypair)(
                 public_key.as_mut_ptr() as *mut c_uchar,
                 secret_key.as_mut_ptr() as *mut c_uchar,
             )
@@ -44,21 +53,22 @@ impl Kyber512 {
             return Err(PqcError::KeyGeneration(result));
         }
 
-        Ok((public_key, secret_key))
+        Ok((
+            Kyber512PublicKey::from_vec_unchecked(public_key),
+            Kyber512SecretKey::from_vec_unchecked(secret_key),
+        ))
     }
 
     /// Encapsulate a shared secret
-    pub fn encapsulate(&self, public_key: &[u8]) -> PqcResult<(Vec<u8>, Vec<u8>)> {
-        validate_length(public_key, Self::PUBLIC_KEY_BYTES, "public key")?;
-
+    pub fn encapsulate(&self, public_key: &Kyber512PublicKey) -> PqcResult<(Kyber512Ciphertext, Kyber512SharedSecret)> {
         let mut ciphertext = vec![0u8; Self::CIPHERTEXT_BYTES];
         let mut shared_secret = vec![0u8; Self::SHARED_SECRET_BYTES];
 
         let result = unsafe {
-            crate::PQCLEAN_KYBER512_CLEAN_crypto_kem_enc(
+            (self.handle.kyber512_enc)(
                 ciphertext.as_mut_ptr() as *mut c_uchar,
                 shared_secret.as_mut_ptr() as *mut c_uchar,
-                public_key.as_ptr() as *const c_uchar,
+                public_key.as_bytes().as_ptr() as *const c_uchar,
             )
         };
 
@@ -66,21 +76,21 @@ impl Kyber512 {
             return Err(PqcError::Encapsulation(result));
         }
 
-        Ok((ciphertext, shared_secret))
+        Ok((
+            Kyber512Ciphertext::from_vec_unchecked(ciphertext),
+            Kyber512SharedSecret::from_vec_unchecked(shared_secret),
+        ))
     }
 
     /// Decapsulate the shared secret
-    pub fn decapsulate(&self, ciphertext: &[u8], secret_key: &[u8]) -> PqcResult<Vec<u8>> {
-        validate_length(ciphertext, Self::CIPHERTEXT_BYTES, "ciphertext")?;
-        validate_length(secret_key, Self::SECRET_KEY_BYTES, "secret key")?;
-
+    pub fn decapsulate(&self, ciphertext: &Kyber512Ciphertext, secret_key: &Kyber512SecretKey) -> PqcResult<Kyber512SharedSecret> {
         let mut shared_secret = vec![0u8; Self::SHARED_SECRET_BYTES];
 
         let result = unsafe {
-            crate::PQCLEAN_KYBER512_CLEAN_crypto_kem_dec(
+            (self.handle.kyber512_dec)(
                 shared_secret.as_mut_ptr() as *mut c_uchar,
-                ciphertext.as_ptr() as *const c_uchar,
-                secret_key.as_ptr() as *const c_uchar,
+                ciphertext.as_bytes().as_ptr() as *const c_uchar,
+                secret_key.as_bytes().as_ptr() as *const c_uchar,
             )
         };
 
@@ -88,21 +98,86 @@ impl Kyber512 {
             return Err(PqcError::Decapsulation(result));
         }
 
-        Ok(shared_secret)
+        Ok(Kyber512SharedSecret::from_vec_unchecked(shared_secret))
+    }
+
+    /// Generate a key pair deterministically from a 48-byte NIST KAT seed.
+    ///
+    /// Seeds the PQClean reference DRBG before calling [`Kyber512::keypair`],
+    /// so this reproduces a `.rsp` vector's `pk`/`sk` byte-for-byte. See the
+    /// [`crate::kat`] module.
+    ///
+    /// NIST's KAT generator seeds the DRBG once per vector, then
+    /// draws keygen and encapsulation randomness from that same
+    /// continuing stream — so a plain [`Kyber512::encapsulate`] call
+    /// right after this one reproduces the vector's `ct`/`ss` too;
+    /// seeding again in between would reset the stream to bytes
+    /// keygen already consumed.
+    pub fn keypair_from_seed(&self, seed: &[u8; 48]) -> PqcResult<(Kyber512PublicKey, Kyber512SecretKey)> {
+        crate::seed_drbg(seed)?;
+        self.keypair()
+    }
+
+    /// Encapsulate a shared secret deterministically from 48 bytes of entropy.
+    ///
+    /// Seeds the PQClean reference DRBG with `coins` before calling
+    /// [`Kyber512::encapsulate`], so the same `coins` and public key always
+    /// give the same `ct`/`ss`.
+    ///
+    /// Don't call this right after [`Kyber512::keypair_from_seed`] with the
+    /// same seed: NIST's KAT generator seeds the DRBG once per vector and
+    /// draws keygen and encapsulation randomness from that same continuing
+    /// stream, so re-seeding here would reset it to bytes keygen already
+    /// consumed. The [`crate::kat`] harness instead calls plain
+    /// [`Kyber512::encapsulate`] after `keypair_from_seed`.
+    pub fn encapsulate_deterministic(&self, public_key: &Kyber512PublicKey, coins: &[u8; 48]) -> PqcResult<(Kyber512Ciphertext, Kyber512SharedSecret)> {
+        crate::seed_drbg(coins)?;
+        self.encapsulate(public_key)
+    }
+}
+
+impl Kem for Kyber512 {
+    type PublicKey = Kyber512PublicKey;
+    type SecretKey = Kyber512SecretKey;
+    type Ciphertext = Kyber512Ciphertext;
+    type SharedSecret = Kyber512SharedSecret;
+
+    const PUBLIC_KEY_BYTES: usize = Kyber512::PUBLIC_KEY_BYTES;
+    const SECRET_KEY_BYTES: usize = Kyber512::SECRET_KEY_BYTES;
+    const CIPHERTEXT_BYTES: usize = Kyber512::CIPHERTEXT_BYTES;
+    const SHARED_SECRET_BYTES: usize = Kyber512::SHARED_SECRET_BYTES;
+
+    fn keypair(&self) -> PqcResult<(Self::PublicKey, Self::SecretKey)> {
+        Kyber512::keypair(self)
+    }
+
+    fn encapsulate(&self, public_key: &Self::PublicKey) -> PqcResult<(Self::Ciphertext, Self::SharedSecret)> {
+        Kyber512::encapsulate(self, public_key)
+    }
+
+    fn decapsulate(&self, ciphertext: &Self::Ciphertext, secret_key: &Self::SecretKey) -> PqcResult<Self::SharedSecret> {
+        Kyber512::decapsulate(self, ciphertext, secret_key)
     }
 }
 
 /// Kyber768 Key Encapsulation Mechanism
-/// 
+///
 /// Security Level: 3 (equivalent to AES-192)
 /// - Public Key: 1184 bytes
 /// - Secret Key: 2400 bytes
 /// - Ciphertext: 1088 bytes
 /// - Shared Secret: 32 bytes
 pub struct Kyber768 {
-    _private: (),
+    handle: &'static crate::loader::Handle,
 }
 
+simple_struct!(Kyber768PublicKey, Kyber768::PUBLIC_KEY_BYTES);
+impl_encoding!(Kyber768PublicKey, KeyOid::Kyber768Public);
+simple_struct!(Kyber768SecretKey, Kyber768::SECRET_KEY_BYTES);
+impl_encoding!(Kyber768SecretKey, KeyOid::Kyber768Secret);
+simple_struct!(Kyber768Ciphertext, Kyber768::CIPHERTEXT_BYTES);
+simple_struct!(Kyber768SharedSecret, Kyber768::SHARED_SECRET_BYTES);
+
 impl Kyber768 {
     /// Algorithm constants
     pub const PUBLIC_KEY_BYTES: usize = 1184;
@@ -112,17 +187,17 @@ impl Kyber768 {
 
     /// Create a new Kyber768 instance
     pub fn new() -> PqcResult<Self> {
-        crate::find_binary_path()?;
-        Ok(Self { _private: () })
+        let handle = crate::loader::handle()?;
+        Ok(Self { handle })
     }
 
     /// Generate a key pair
-    pub fn keypair(&self) -> PqcResult<(Vec<u8>, Vec<u8>)> {
+    pub fn keypair(&self) -> PqcResult<(Kyber768PublicKey, Kyber768SecretKey)> {
         let mut public_key = vec![0u8; Self::PUBLIC_KEY_BYTES];
         let mut secret_key = vec![0u8; Self::SECRET_KEY_BYTES];
 
         let result = unsafe {
-            crate::PQCLEAN_KYBER768_CLEAN_crypto_kem_keypair(
+            (self.handle.kyber768_keypair)(
                 public_key.as_mut_ptr() as *mut c_uchar,
                 secret_key.as_mut_ptr() as *mut c_uchar,
             )
@@ -132,21 +207,22 @@ impl Kyber768 {
             return Err(PqcError::KeyGeneration(result));
         }
 
-        Ok((public_key, secret_key))
+        Ok((
+            Kyber768PublicKey::from_vec_unchecked(public_key),
+            Kyber768SecretKey::from_vec_unchecked(secret_key),
+        ))
     }
 
     /// Encapsulate a shared secret
-    pub fn encapsulate(&self, public_key: &[u8]) -> PqcResult<(Vec<u8>, Vec<u8>)> {
-        validate_length(public_key, Self::PUBLIC_KEY_BYTES, "public key")?;
-
+    pub fn encapsulate(&self, public_key: &Kyber768PublicKey) -> PqcResult<(Kyber768Ciphertext, Kyber768SharedSecret)> {
         let mut ciphertext = vec![0u8; Self::CIPHERTEXT_BYTES];
         let mut shared_secret = vec![0u8; Self::SHARED_SECRET_BYTES];
 
         let result = unsafe {
-            crate::PQCLEAN_KYBER768_CLEAN_crypto_kem_enc(
+            (self.handle.kyber768_enc)(
                 ciphertext.as_mut_ptr() as *mut c_uchar,
                 shared_secret.as_mut_ptr() as *mut c_uchar,
-                public_key.as_ptr() as *const c_uchar,
+                public_key.as_bytes().as_ptr() as *const c_uchar,
             )
         };
 
@@ -154,21 +230,21 @@ impl Kyber768 {
             return Err(PqcError::Encapsulation(result));
         }
 
-        Ok((ciphertext, shared_secret))
+        Ok((
+            Kyber768Ciphertext::from_vec_unchecked(ciphertext),
+            Kyber768SharedSecret::from_vec_unchecked(shared_secret),
+        ))
     }
 
     /// Decapsulate the shared secret
-    pub fn decapsulate(&self, ciphertext: &[u8], secret_key: &[u8]) -> PqcResult<Vec<u8>> {
-        validate_length(ciphertext, Self::CIPHERTEXT_BYTES, "ciphertext")?;
-        validate_length(secret_key, Self::SECRET_KEY_BYTES, "secret key")?;
-
+    pub fn decapsulate(&self, ciphertext: &Kyber768Ciphertext, secret_key: &Kyber768SecretKey) -> PqcResult<Kyber768SharedSecret> {
         let mut shared_secret = vec![0u8; Self::SHARED_SECRET_BYTES];
 
         let result = unsafe {
-            crate::PQCLEAN_KYBER768_CLEAN_crypto_kem_dec(
+            (self.handle.kyber768_dec)(
                 shared_secret.as_mut_ptr() as *mut c_uchar,
-                ciphertext.as_ptr() as *const c_uchar,
-                secret_key.as_ptr() as *const c_uchar,
+                ciphertext.as_bytes().as_ptr() as *const c_uchar,
+                secret_key.as_bytes().as_ptr() as *const c_uchar,
             )
         };
 
@@ -176,21 +252,86 @@ impl Kyber768 {
             return Err(PqcError::Decapsulation(result));
         }
 
-        Ok(shared_secret)
+        Ok(Kyber768SharedSecret::from_vec_unchecked(shared_secret))
+    }
+
+    /// Generate a key pair deterministically from a 48-byte NIST KAT seed.
+    ///
+    /// Seeds the PQClean reference DRBG before calling [`Kyber768::keypair`],
+    /// so this reproduces a `.rsp` vector's `pk`/`sk` byte-for-byte. See the
+    /// [`crate::kat`] module.
+    ///
+    /// NIST's KAT generator seeds the DRBG once per vector, then
+    /// draws keygen and encapsulation randomness from that same
+    /// continuing stream — so a plain [`Kyber768::encapsulate`] call
+    /// right after this one reproduces the vector's `ct`/`ss` too;
+    /// seeding again in between would reset the stream to bytes
+    /// keygen already consumed.
+    pub fn keypair_from_seed(&self, seed: &[u8; 48]) -> PqcResult<(Kyber768PublicKey, Kyber768SecretKey)> {
+        crate::seed_drbg(seed)?;
+        self.keypair()
+    }
+
+    /// Encapsulate a shared secret deterministically from 48 bytes of entropy.
+    ///
+    /// Seeds the PQClean reference DRBG with `coins` before calling
+    /// [`Kyber768::encapsulate`], so the same `coins` and public key always
+    /// give the same `ct`/`ss`.
+    ///
+    /// Don't call this right after [`Kyber768::keypair_from_seed`] with the
+    /// same seed: NIST's KAT generator seeds the DRBG once per vector and
+    /// draws keygen and encapsulation randomness from that same continuing
+    /// stream, so re-seeding here would reset it to bytes keygen already
+    /// consumed. The [`crate::kat`] harness instead calls plain
+    /// [`Kyber768::encapsulate`] after `keypair_from_seed`.
+    pub fn encapsulate_deterministic(&self, public_key: &Kyber768PublicKey, coins: &[u8; 48]) -> PqcResult<(Kyber768Ciphertext, Kyber768SharedSecret)> {
+        crate::seed_drbg(coins)?;
+        self.encapsulate(public_key)
+    }
+}
+
+impl Kem for Kyber768 {
+    type PublicKey = Kyber768PublicKey;
+    type SecretKey = Kyber768SecretKey;
+    type Ciphertext = Kyber768Ciphertext;
+    type SharedSecret = Kyber768SharedSecret;
+
+    const PUBLIC_KEY_BYTES: usize = Kyber768::PUBLIC_KEY_BYTES;
+    const SECRET_KEY_BYTES: usize = Kyber768::SECRET_KEY_BYTES;
+    const CIPHERTEXT_BYTES: usize = Kyber768::CIPHERTEXT_BYTES;
+    const SHARED_SECRET_BYTES: usize = Kyber768::SHARED_SECRET_BYTES;
+
+    fn keypair(&self) -> PqcResult<(Self::PublicKey, Self::SecretKey)> {
+        Kyber768::keypair(self)
+    }
+
+    fn encapsulate(&self, public_key: &Self::PublicKey) -> PqcResult<(Self::Ciphertext, Self::SharedSecret)> {
+        Kyber768::encapsulate(self, public_key)
+    }
+
+    fn decapsulate(&self, ciphertext: &Self::Ciphertext, secret_key: &Self::SecretKey) -> PqcResult<Self::SharedSecret> {
+        Kyber768::decapsulate(self, ciphertext, secret_key)
     }
 }
 
 /// Kyber1024 Key Encapsulation Mechanism
-/// 
+///
 /// Security Level: 5 (equivalent to AES-256)
 /// - Public Key: 1568 bytes
 /// - Secret Key: 3168 bytes
 /// - Ciphertext: 1568 bytes
 /// - Shared Secret: 32 bytes
 pub struct Kyber1024 {
-    _private: (),
+    handle: &'static crate::loader::Handle,
 }
 
+simple_struct!(Kyber1024PublicKey, Kyber1024::PUBLIC_KEY_BYTES);
+impl_encoding!(Kyber1024PublicKey, KeyOid::Kyber1024Public);
+simple_struct!(Kyber1024SecretKey, Kyber1024::SECRET_KEY_BYTES);
+impl_encoding!(Kyber1024SecretKey, KeyOid::Kyber1024Secret);
+simple_struct!(Kyber1024Ciphertext, Kyber1024::CIPHERTEXT_BYTES);
+simple_struct!(Kyber1024SharedSecret, Kyber1024::SHARED_SECRET_BYTES);
+
 impl Kyber1024 {
     /// Algorithm constants
     pub const PUBLIC_KEY_BYTES: usize = 1568;
@@ -200,17 +341,17 @@ impl Kyber1024 {
 
     /// Create a new Kyber1024 instance
     pub fn new() -> PqcResult<Self> {
-        crate::find_binary_path()?;
-        Ok(Self { _private: () })
+        let handle = crate::loader::handle()?;
+        Ok(Self { handle })
     }
 
     /// Generate a key pair
-    pub fn keypair(&self) -> PqcResult<(Vec<u8>, Vec<u8>)> {
+    pub fn keypair(&self) -> PqcResult<(Kyber1024PublicKey, Kyber1024SecretKey)> {
         let mut public_key = vec![0u8; Self::PUBLIC_KEY_BYTES];
         let mut secret_key = vec![0u8; Self::SECRET_KEY_BYTES];
 
         let result = unsafe {
-            crate::PQCLEAN_KYBER1024_CLEAN_crypto_kem_keypair(
+            (self.handle.kyber1024_keypair)(
                 public_key.as_mut_ptr() as *mut c_uchar,
                 secret_key.as_mut_ptr() as *mut c_uchar,
             )
@@ -220,21 +361,22 @@ impl Kyber1024 {
             return Err(PqcError::KeyGeneration(result));
         }
 
-        Ok((public_key, secret_key))
+        Ok((
+            Kyber1024PublicKey::from_vec_unchecked(public_key),
+            Kyber1024SecretKey::from_vec_unchecked(secret_key),
+        ))
     }
 
     /// Encapsulate a shared secret
-    pub fn encapsulate(&self, public_key: &[u8]) -> PqcResult<(Vec<u8>, Vec<u8>)> {
-        validate_length(public_key, Self::PUBLIC_KEY_BYTES, "public key")?;
-
+    pub fn encapsulate(&self, public_key: &Kyber1024PublicKey) -> PqcResult<(Kyber1024Ciphertext, Kyber1024SharedSecret)> {
         let mut ciphertext = vec![0u8; Self::CIPHERTEXT_BYTES];
         let mut shared_secret = vec![0u8; Self::SHARED_SECRET_BYTES];
 
         let result = unsafe {
-            crate::PQCLEAN_KYBER1024_CLEAN_crypto_kem_enc(
+            (self.handle.kyber1024_enc)(
                 ciphertext.as_mut_ptr() as *mut c_uchar,
                 shared_secret.as_mut_ptr() as *mut c_uchar,
-                public_key.as_ptr() as *const c_uchar,
+                public_key.as_bytes().as_ptr() as *const c_uchar,
             )
         };
 
@@ -242,21 +384,21 @@ impl Kyber1024 {
             return Err(PqcError::Encapsulation(result));
         }
 
-        Ok((ciphertext, shared_secret))
+        Ok((
+            Kyber1024Ciphertext::from_vec_unchecked(ciphertext),
+            Kyber1024SharedSecret::from_vec_unchecked(shared_secret),
+        ))
     }
 
     /// Decapsulate the shared secret
-    pub fn decapsulate(&self, ciphertext: &[u8], secret_key: &[u8]) -> PqcResult<Vec<u8>> {
-        validate_length(ciphertext, Self::CIPHERTEXT_BYTES, "ciphertext")?;
-        validate_length(secret_key, Self::SECRET_KEY_BYTES, "secret key")?;
-
+    pub fn decapsulate(&self, ciphertext: &Kyber1024Ciphertext, secret_key: &Kyber1024SecretKey) -> PqcResult<Kyber1024SharedSecret> {
         let mut shared_secret = vec![0u8; Self::SHARED_SECRET_BYTES];
 
         let result = unsafe {
-            crate::PQCLEAN_KYBER1024_CLEAN_crypto_kem_dec(
+            (self.handle.kyber1024_dec)(
                 shared_secret.as_mut_ptr() as *mut c_uchar,
-                ciphertext.as_ptr() as *const c_uchar,
-                secret_key.as_ptr() as *const c_uchar,
+                ciphertext.as_bytes().as_ptr() as *const c_uchar,
+                secret_key.as_bytes().as_ptr() as *const c_uchar,
             )
         };
 
@@ -264,7 +406,65 @@ impl Kyber1024 {
             return Err(PqcError::Decapsulation(result));
         }
 
-        Ok(shared_secret)
+        Ok(Kyber1024SharedSecret::from_vec_unchecked(shared_secret))
+    }
+
+    /// Generate a key pair deterministically from a 48-byte NIST KAT seed.
+    ///
+    /// Seeds the PQClean reference DRBG before calling [`Kyber1024::keypair`],
+    /// so this reproduces a `.rsp` vector's `pk`/`sk` byte-for-byte. See the
+    /// [`crate::kat`] module.
+    ///
+    /// NIST's KAT generator seeds the DRBG once per vector, then
+    /// draws keygen and encapsulation randomness from that same
+    /// continuing stream — so a plain [`Kyber1024::encapsulate`] call
+    /// right after this one reproduces the vector's `ct`/`ss` too;
+    /// seeding again in between would reset the stream to bytes
+    /// keygen already consumed.
+    pub fn keypair_from_seed(&self, seed: &[u8; 48]) -> PqcResult<(Kyber1024PublicKey, Kyber1024SecretKey)> {
+        crate::seed_drbg(seed)?;
+        self.keypair()
+    }
+
+    /// Encapsulate a shared secret deterministically from 48 bytes of entropy.
+    ///
+    /// Seeds the PQClean reference DRBG with `coins` before calling
+    /// [`Kyber1024::encapsulate`], so the same `coins` and public key always
+    /// give the same `ct`/`ss`.
+    ///
+    /// Don't call this right after [`Kyber1024::keypair_from_seed`] with the
+    /// same seed: NIST's KAT generator seeds the DRBG once per vector and
+    /// draws keygen and encapsulation randomness from that same continuing
+    /// stream, so re-seeding here would reset it to bytes keygen already
+    /// consumed. The [`crate::kat`] harness instead calls plain
+    /// [`Kyber1024::encapsulate`] after `keypair_from_seed`.
+    pub fn encapsulate_deterministic(&self, public_key: &Kyber1024PublicKey, coins: &[u8; 48]) -> PqcResult<(Kyber1024Ciphertext, Kyber1024SharedSecret)> {
+        crate::seed_drbg(coins)?;
+        self.encapsulate(public_key)
+    }
+}
+
+impl Kem for Kyber1024 {
+    type PublicKey = Kyber1024PublicKey;
+    type SecretKey = Kyber1024SecretKey;
+    type Ciphertext = Kyber1024Ciphertext;
+    type SharedSecret = Kyber1024SharedSecret;
+
+    const PUBLIC_KEY_BYTES: usize = Kyber1024::PUBLIC_KEY_BYTES;
+    const SECRET_KEY_BYTES: usize = Kyber1024::SECRET_KEY_BYTES;
+    const CIPHERTEXT_BYTES: usize = Kyber1024::CIPHERTEXT_BYTES;
+    const SHARED_SECRET_BYTES: usize = Kyber1024::SHARED_SECRET_BYTES;
+
+    fn keypair(&self) -> PqcResult<(Self::PublicKey, Self::SecretKey)> {
+        Kyber1024::keypair(self)
+    }
+
+    fn encapsulate(&self, public_key: &Self::PublicKey) -> PqcResult<(Self::Ciphertext, Self::SharedSecret)> {
+        Kyber1024::encapsulate(self, public_key)
+    }
+
+    fn decapsulate(&self, ciphertext: &Self::Ciphertext, secret_key: &Self::SecretKey) -> PqcResult<Self::SharedSecret> {
+        Kyber1024::decapsulate(self, ciphertext, secret_key)
     }
 }
 
@@ -279,17 +479,17 @@ mod tests {
     fn test_kyber512() -> PqcResult<()> {
         let kyber = Kyber512::new()?;
         let (pk, sk) = kyber.keypair()?;
-        
-        assert_eq!(pk.len(), Kyber512::PUBLIC_KEY_BYTES);
-        assert_eq!(sk.len(), Kyber512::SECRET_KEY_BYTES);
-        
+
+        assert_eq!(pk.as_bytes().len(), Kyber512::PUBLIC_KEY_BYTES);
+        assert_eq!(sk.as_bytes().len(), Kyber512::SECRET_KEY_BYTES);
+
         let (ct, ss1) = kyber.encapsulate(&pk)?;
-        assert_eq!(ct.len(), Kyber512::CIPHERTEXT_BYTES);
-        assert_eq!(ss1.len(), Kyber512::SHARED_SECRET_BYTES);
-        
+        assert_eq!(ct.as_bytes().len(), Kyber512::CIPHERTEXT_BYTES);
+        assert_eq!(ss1.as_bytes().len(), Kyber512::SHARED_SECRET_BYTES);
+
         let ss2 = kyber.decapsulate(&ct, &sk)?;
         assert_eq!(ss1, ss2);
-        
+
         Ok(())
     }
 
@@ -297,17 +497,17 @@ mod tests {
     fn test_kyber768() -> PqcResult<()> {
         let kyber = Kyber768::new()?;
         let (pk, sk) = kyber.keypair()?;
-        
-        assert_eq!(pk.len(), Kyber768::PUBLIC_KEY_BYTES);
-        assert_eq!(sk.len(), Kyber768::SECRET_KEY_BYTES);
-        
+
+        assert_eq!(pk.as_bytes().len(), Kyber768::PUBLIC_KEY_BYTES);
+        assert_eq!(sk.as_bytes().len(), Kyber768::SECRET_KEY_BYTES);
+
         let (ct, ss1) = kyber.encapsulate(&pk)?;
-        assert_eq!(ct.len(), Kyber768::CIPHERTEXT_BYTES);
-        assert_eq!(ss1.len(), Kyber768::SHARED_SECRET_BYTES);
-        
+        assert_eq!(ct.as_bytes().len(), Kyber768::CIPHERTEXT_BYTES);
+        assert_eq!(ss1.as_bytes().len(), Kyber768::SHARED_SECRET_BYTES);
+
         let ss2 = kyber.decapsulate(&ct, &sk)?;
         assert_eq!(ss1, ss2);
-        
+
         Ok(())
     }
 
@@ -315,17 +515,23 @@ mod tests {
     fn test_kyber1024() -> PqcResult<()> {
         let kyber = Kyber1024::new()?;
         let (pk, sk) = kyber.keypair()?;
-        
-        assert_eq!(pk.len(), Kyber1024::PUBLIC_KEY_BYTES);
-        assert_eq!(sk.len(), Kyber1024::SECRET_KEY_BYTES);
-        
+
+        assert_eq!(pk.as_bytes().len(), Kyber1024::PUBLIC_KEY_BYTES);
+        assert_eq!(sk.as_bytes().len(), Kyber1024::SECRET_KEY_BYTES);
+
         let (ct, ss1) = kyber.encapsulate(&pk)?;
-        assert_eq!(ct.len(), Kyber1024::CIPHERTEXT_BYTES);
-        assert_eq!(ss1.len(), Kyber1024::SHARED_SECRET_BYTES);
-        
+        assert_eq!(ct.as_bytes().len(), Kyber1024::CIPHERTEXT_BYTES);
+        assert_eq!(ss1.as_bytes().len(), Kyber1024::SHARED_SECRET_BYTES);
+
         let ss2 = kyber.decapsulate(&ct, &sk)?;
         assert_eq!(ss1, ss2);
-        
+
         Ok(())
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_typed_keys_reject_wrong_length() {
+        assert!(Kyber512PublicKey::from_bytes(&[0u8; 10]).is_err());
+        assert!(Kyber512PublicKey::from_bytes(&[0u8; Kyber512::PUBLIC_KEY_BYTES]).is_ok());
+    }
+}
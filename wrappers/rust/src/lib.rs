@@ -24,6 +24,9 @@
 //! let decrypted_secret = kyber.decapsulate(&ciphertext, &secret_key)?;
 //! assert_eq!(shared_secret, decrypted_secret);
 //!
+//! // The above works identically over any `Kem` implementor, e.g.
+//! // a function written as `fn do_stuff<K: pqchub::Kem>(kem: &K)`.
+//!
 //! // Digital Signatures
 //! let dilithium = Dilithium2::new()?;
 //! let (pk, sk) = dilithium.keypair()?;
@@ -34,15 +37,25 @@
 //! ```
 
 use std::env;
-use std::path::{Path, PathBuf};
-use std::ffi::{CString, c_char, c_int, c_uchar};
+use std::path::PathBuf;
 use thiserror::Error;
 
+pub(crate) mod macros;
+pub mod traits;
 pub mod kyber;
 pub mod dilithium;
+#[cfg(any(feature = "aes", feature = "xchacha"))]
+pub mod hybrid;
+pub mod kat;
+pub mod encoding;
+pub mod algorithm;
+pub mod loader;
+
+pub use algorithm::{kem_keypair, sign, Algorithm};
 
 pub use kyber::{Kyber512, Kyber768, Kyber1024, Kyber};
 pub use dilithium::{Dilithium2, Dilithium3, Dilithium5, Dilithium};
+pub use traits::{Kem, Signature};
 
 /// Library version
 pub const VERSION: &str = "1.0.0";
@@ -67,10 +80,22 @@ pub enum PqcError {
     
     #[error("Signature verification failed with code {0}")]
     Verification(i32),
-    
+
     #[error("Invalid key size: expected {expected}, got {actual}")]
     InvalidKeySize { expected: usize, actual: usize },
-    
+
+    #[error("AEAD operation failed: {0}")]
+    Aead(String),
+
+    #[error("Encoding error: {0}")]
+    Encoding(String),
+
+    #[error("Invalid algorithm: {0}")]
+    InvalidAlgorithm(String),
+
+    #[error("Symbol not found in native library: {0}")]
+    SymbolNotFound(String),
+
     #[error("Platform not supported: {0}")]
     UnsupportedPlatform(String),
     
@@ -89,9 +114,10 @@ pub struct PlatformInfo {
     pub binary_path: Option<PathBuf>,
 }
 
-/// Get current platform information
-pub fn get_platform_info() -> PlatformInfo {
-    let (system, architecture) = if cfg!(target_os = "windows") {
+/// Detect the current OS/architecture pair, in the naming this crate
+/// uses for its `bins/<system>-<architecture>/` layout.
+fn detect_platform() -> (String, String) {
+    if cfg!(target_os = "windows") {
         if cfg!(target_arch = "x86_64") {
             ("windows".to_string(), "x64".to_string())
         } else if cfg!(target_arch = "x86") {
@@ -117,8 +143,12 @@ pub fn get_platform_info() -> PlatformInfo {
         }
     } else {
         (env::consts::OS.to_string(), env::consts::ARCH.to_string())
-    };
+    }
+}
 
+/// Get current platform information
+pub fn get_platform_info() -> PlatformInfo {
+    let (system, architecture) = detect_platform();
     let binary_path = find_binary_path().ok();
 
     PlatformInfo {
@@ -130,28 +160,28 @@ pub fn get_platform_info() -> PlatformInfo {
 
 /// Find the PQC native library for the current platform
 pub fn find_binary_path() -> PqcResult<PathBuf> {
-    let info = get_platform_info();
-    
+    let (system, architecture) = detect_platform();
+
     // Determine platform directory name
-    let platform_dir = match info.system.as_str() {
-        "macos" => format!("macos-{}", info.architecture),
+    let platform_dir = match system.as_str() {
+        "macos" => format!("macos-{}", architecture),
         "windows" => {
-            if info.architecture == "x86_64" || info.architecture == "x64" {
+            if architecture == "x86_64" || architecture == "x64" {
                 "windows-x64".to_string()
-            } else if info.architecture == "x86" {
+            } else if architecture == "x86" {
                 "windows-x86".to_string()
             } else {
                 return Err(PqcError::UnsupportedPlatform(format!(
-                    "Windows architecture: {}", info.architecture
+                    "Windows architecture: {}", architecture
                 )));
             }
         }
-        "linux" => format!("linux-{}", info.architecture),
-        _ => return Err(PqcError::UnsupportedPlatform(info.system)),
+        "linux" => format!("linux-{}", architecture),
+        _ => return Err(PqcError::UnsupportedPlatform(system)),
     };
 
     // Determine library name
-    let lib_name = match info.system.as_str() {
+    let lib_name = match system.as_str() {
         "windows" => "pqc.dll",
         "macos" => "libpqc.dylib",
         _ => "libpqc.so",
@@ -182,7 +212,7 @@ pub fn find_binary_path() -> PqcResult<PathBuf> {
 }
 
 /// Validate that a slice has the expected length
-pub fn validate_length(data: &[u8], expected: usize, name: &str) -> PqcResult<()> {
+pub fn validate_length(data: &[u8], expected: usize, _name: &str) -> PqcResult<()> {
     if data.len() != expected {
         Err(PqcError::InvalidKeySize {
             expected,
@@ -193,107 +223,27 @@ pub fn validate_length(data: &[u8], expected: usize, name: &str) -> PqcResult<()
     }
 }
 
-/// External function declarations for the PQC library
-extern "C" {
-    // Library info functions
-    fn pqchub_get_version() -> *const c_char;
-    fn pqchub_get_algorithms() -> *const c_char;
-    
-    // Kyber512 functions
-    fn PQCLEAN_KYBER512_CLEAN_crypto_kem_keypair(pk: *mut c_uchar, sk: *mut c_uchar) -> c_int;
-    fn PQCLEAN_KYBER512_CLEAN_crypto_kem_enc(
-        ct: *mut c_uchar, 
-        ss: *mut c_uchar, 
-        pk: *const c_uchar
-    ) -> c_int;
-    fn PQCLEAN_KYBER512_CLEAN_crypto_kem_dec(
-        ss: *mut c_uchar, 
-        ct: *const c_uchar, 
-        sk: *const c_uchar
-    ) -> c_int;
-    
-    // Kyber768 functions
-    fn PQCLEAN_KYBER768_CLEAN_crypto_kem_keypair(pk: *mut c_uchar, sk: *mut c_uchar) -> c_int;
-    fn PQCLEAN_KYBER768_CLEAN_crypto_kem_enc(
-        ct: *mut c_uchar, 
-        ss: *mut c_uchar, 
-        pk: *const c_uchar
-    ) -> c_int;
-    fn PQCLEAN_KYBER768_CLEAN_crypto_kem_dec(
-        ss: *mut c_uchar, 
-        ct: *const c_uchar, 
-        sk: *const c_uchar
-    ) -> c_int;
-    
-    // Kyber1024 functions
-    fn PQCLEAN_KYBER1024_CLEAN_crypto_kem_keypair(pk: *mut c_uchar, sk: *mut c_uchar) -> c_int;
-    fn PQCLEAN_KYBER1024_CLEAN_crypto_kem_enc(
-        ct: *mut c_uchar, 
-        ss: *mut c_uchar, 
-        pk: *const c_uchar
-    ) -> c_int;
-    fn PQCLEAN_KYBER1024_CLEAN_crypto_kem_dec(
-        ss: *mut c_uchar, 
-        ct: *const c_uchar, 
-        sk: *const c_uchar
-    ) -> c_int;
-    
-    // Dilithium2 functions
-    fn PQCLEAN_DILITHIUM2_CLEAN_crypto_sign_keypair(pk: *mut c_uchar, sk: *mut c_uchar) -> c_int;
-    fn PQCLEAN_DILITHIUM2_CLEAN_crypto_sign_signature(
-        sig: *mut c_uchar,
-        siglen: *mut usize,
-        m: *const c_uchar,
-        mlen: usize,
-        sk: *const c_uchar,
-    ) -> c_int;
-    fn PQCLEAN_DILITHIUM2_CLEAN_crypto_sign_verify(
-        sig: *const c_uchar,
-        siglen: usize,
-        m: *const c_uchar,
-        mlen: usize,
-        pk: *const c_uchar,
-    ) -> c_int;
-    
-    // Dilithium3 functions
-    fn PQCLEAN_DILITHIUM3_CLEAN_crypto_sign_keypair(pk: *mut c_uchar, sk: *mut c_uchar) -> c_int;
-    fn PQCLEAN_DILITHIUM3_CLEAN_crypto_sign_signature(
-        sig: *mut c_uchar,
-        siglen: *mut usize,
-        m: *const c_uchar,
-        mlen: usize,
-        sk: *const c_uchar,
-    ) -> c_int;
-    fn PQCLEAN_DILITHIUM3_CLEAN_crypto_sign_verify(
-        sig: *const c_uchar,
-        siglen: usize,
-        m: *const c_uchar,
-        mlen: usize,
-        pk: *const c_uchar,
-    ) -> c_int;
-    
-    // Dilithium5 functions
-    fn PQCLEAN_DILITHIUM5_CLEAN_crypto_sign_keypair(pk: *mut c_uchar, sk: *mut c_uchar) -> c_int;
-    fn PQCLEAN_DILITHIUM5_CLEAN_crypto_sign_signature(
-        sig: *mut c_uchar,
-        siglen: *mut usize,
-        m: *const c_uchar,
-        mlen: usize,
-        sk: *const c_uchar,
-    ) -> c_int;
-    fn PQCLEAN_DILITHIUM5_CLEAN_crypto_sign_verify(
-        sig: *const c_uchar,
-        siglen: usize,
-        m: *const c_uchar,
-        mlen: usize,
-        pk: *const c_uchar,
-    ) -> c_int;
+/// Seed the PQClean reference DRBG for deterministic NIST KAT vectors.
+///
+/// PQClean's reference code draws entropy from a replaceable
+/// `randombytes()` symbol backed by an AES-256-CTR DRBG. Seeding that
+/// DRBG with a KAT vector's 48-byte `seed` before calling the ordinary
+/// keypair/encapsulate/sign functions reproduces the vector's outputs
+/// byte-for-byte. See the [`kat`] module for the test harness that uses
+/// this.
+pub fn seed_drbg(seed: &[u8; 48]) -> PqcResult<()> {
+    let handle = loader::handle()?;
+    unsafe {
+        (handle.randombytes_init)(seed.as_ptr(), std::ptr::null(), 256);
+    }
+    Ok(())
 }
 
 /// Get library version information
 pub fn get_library_version() -> Option<String> {
+    let handle = loader::handle().ok()?;
     unsafe {
-        let version_ptr = pqchub_get_version();
+        let version_ptr = (handle.get_version)();
         if version_ptr.is_null() {
             None
         } else {
@@ -303,17 +253,12 @@ pub fn get_library_version() -> Option<String> {
     }
 }
 
-/// Get supported algorithms
+/// Get supported algorithms, comma-separated.
+///
+/// Sourced from the same [`Algorithm::ALL`] list [`get_info`]'s
+/// `algorithms` section uses, so the two can never drift apart.
 pub fn get_algorithms() -> Option<String> {
-    unsafe {
-        let algorithms_ptr = pqchub_get_algorithms();
-        if algorithms_ptr.is_null() {
-            None
-        } else {
-            let c_str = std::ffi::CStr::from_ptr(algorithms_ptr);
-            c_str.to_str().ok().map(|s| s.to_string())
-        }
-    }
+    Some(Algorithm::ALL.iter().map(|a| a.name()).collect::<Vec<_>>().join(","))
 }
 
 /// Get comprehensive library information
@@ -332,8 +277,8 @@ pub fn get_info() -> serde_json::Value {
             "algorithms": get_algorithms()
         },
         "algorithms": {
-            "kem": ["Kyber512", "Kyber768", "Kyber1024"],
-            "signatures": ["Dilithium2", "Dilithium3", "Dilithium5"]
+            "kem": Algorithm::ALL.iter().filter(|a| a.is_kem()).map(|a| a.name()).collect::<Vec<_>>(),
+            "signatures": Algorithm::ALL.iter().filter(|a| !a.is_kem()).map(|a| a.name()).collect::<Vec<_>>()
         }
     })
 }
@@ -0,0 +1,203 @@
+//! Runtime dynamic loading of the native PQClean library.
+//!
+//! Earlier versions of this crate linked every `PQCLEAN_*` symbol
+//! statically and only used [`crate::find_binary_path`] to sanity-check
+//! that a `bins/<platform>/` file existed. That breaks the moment the
+//! crate is vendored as a dependency or the `bins/` layout moves,
+//! because the linker has already baked in an assumption about where
+//! the native library lives.
+//!
+//! This module instead opens the platform shared library at runtime
+//! with `libloading` (the same approach Mozilla's osclientcerts uses to
+//! bind platform PKCS#11 modules) and resolves each export into a typed
+//! function pointer held on a lazily-initialized [`Handle`]. Every
+//! algorithm constructor (`Kyber512::new()`, etc.) just borrows a
+//! reference to this shared handle instead of re-checking that a file
+//! exists.
+//!
+//! Candidate directories are tried in order:
+//! 1. `PQCHUB_LIB_DIR`, if set — an explicit override.
+//! 2. The bare library name, left for the OS loader's own search path
+//!    (`LD_LIBRARY_PATH`, `/usr/lib`, `DYLD_LIBRARY_PATH`, `PATH`, ...).
+//! 3. The conventional `bins/<platform>/` path computed by
+//!    [`crate::find_binary_path`], kept as a last-resort fallback.
+
+use std::env;
+use std::ffi::{c_char, c_int, c_uchar};
+use std::path::PathBuf;
+use std::sync::OnceLock;
+
+use libloading::Library;
+
+use crate::{PqcError, PqcResult};
+
+/// Environment variable naming an extra directory to search for the
+/// native library, checked before the system loader path.
+pub const LIB_DIR_ENV: &str = "PQCHUB_LIB_DIR";
+
+type KemKeypairFn = unsafe extern "C" fn(*mut c_uchar, *mut c_uchar) -> c_int;
+type KemEncFn = unsafe extern "C" fn(*mut c_uchar, *mut c_uchar, *const c_uchar) -> c_int;
+type KemDecFn = unsafe extern "C" fn(*mut c_uchar, *const c_uchar, *const c_uchar) -> c_int;
+type SignKeypairFn = unsafe extern "C" fn(*mut c_uchar, *mut c_uchar) -> c_int;
+type SignSignatureFn =
+    unsafe extern "C" fn(*mut c_uchar, *mut usize, *const c_uchar, usize, *const c_uchar) -> c_int;
+type SignVerifyFn =
+    unsafe extern "C" fn(*const c_uchar, usize, *const c_uchar, usize, *const c_uchar) -> c_int;
+type GetStringFn = unsafe extern "C" fn() -> *const c_char;
+type RandombytesInitFn = unsafe extern "C" fn(*const c_uchar, *const c_uchar, c_int);
+
+/// The native library, opened once, with every symbol this crate calls
+/// resolved into a typed function pointer.
+///
+/// The function pointers borrow from `_library` for as long as `Handle`
+/// is alive, so the two must never be separated; that's the only reason
+/// `_library` is kept as a field instead of being dropped after loading.
+pub struct Handle {
+    _library: Library,
+    pub(crate) get_version: GetStringFn,
+    pub(crate) randombytes_init: RandombytesInitFn,
+    pub(crate) kyber512_keypair: KemKeypairFn,
+    pub(crate) kyber512_enc: KemEncFn,
+    pub(crate) kyber512_dec: KemDecFn,
+    pub(crate) kyber768_keypair: KemKeypairFn,
+    pub(crate) kyber768_enc: KemEncFn,
+    pub(crate) kyber768_dec: KemDecFn,
+    pub(crate) kyber1024_keypair: KemKeypairFn,
+    pub(crate) kyber1024_enc: KemEncFn,
+    pub(crate) kyber1024_dec: KemDecFn,
+    pub(crate) dilithium2_keypair: SignKeypairFn,
+    pub(crate) dilithium2_sign: SignSignatureFn,
+    pub(crate) dilithium2_verify: SignVerifyFn,
+    pub(crate) dilithium3_keypair: SignKeypairFn,
+    pub(crate) dilithium3_sign: SignSignatureFn,
+    pub(crate) dilithium3_verify: SignVerifyFn,
+    pub(crate) dilithium5_keypair: SignKeypairFn,
+    pub(crate) dilithium5_sign: SignSignatureFn,
+    pub(crate) dilithium5_verify: SignVerifyFn,
+}
+
+/// Resolve `name` (a NUL-terminated symbol name) from `library` into a
+/// typed function pointer, or `PqcError::SymbolNotFound` if it's absent.
+unsafe fn resolve<T: Copy>(library: &Library, name: &[u8]) -> PqcResult<T> {
+    library
+        .get::<T>(name)
+        .map(|symbol| *symbol)
+        .map_err(|_| PqcError::SymbolNotFound(String::from_utf8_lossy(&name[..name.len() - 1]).into_owned()))
+}
+
+impl Handle {
+    fn load_from(path: &PathBuf) -> PqcResult<Self> {
+        let library = unsafe {
+            Library::new(path)
+                .map_err(|e| PqcError::LibraryNotFound(format!("{}: {}", path.display(), e)))?
+        };
+
+        unsafe {
+            Ok(Self {
+                get_version: resolve(&library, b"pqchub_get_version\0")?,
+                randombytes_init: resolve(&library, b"randombytes_init\0")?,
+                kyber512_keypair: resolve(&library, b"PQCLEAN_KYBER512_CLEAN_crypto_kem_keypair\0")?,
+                kyber512_enc: resolve(&library, b"PQCLEAN_KYBER512_CLEAN_crypto_kem_enc\0")?,
+                kyber512_dec: resolve(&library, b"PQCLEAN_KYBER512_CLEAN_crypto_kem_dec\0")?,
+                kyber768_keypair: resolve(&library, b"PQCLEAN_KYBER768_CLEAN_crypto_kem_keypair\0")?,
+                kyber768_enc: resolve(&library, b"PQCLEAN_KYBER768_CLEAN_crypto_kem_enc\0")?,
+                kyber768_dec: resolve(&library, b"PQCLEAN_KYBER768_CLEAN_crypto_kem_dec\0")?,
+                kyber1024_keypair: resolve(&library, b"PQCLEAN_KYBER1024_CLEAN_crypto_kem_keypair\0")?,
+                kyber1024_enc: resolve(&library, b"PQCLEAN_KYBER1024_CLEAN_crypto_kem_enc\0")?,
+                kyber1024_dec: resolve(&library, b"PQCLEAN_KYBER1024_CLEAN_crypto_kem_dec\0")?,
+                dilithium2_keypair: resolve(&library, b"PQCLEAN_DILITHIUM2_CLEAN_crypto_sign_keypair\0")?,
+                dilithium2_sign: resolve(&library, b"PQCLEAN_DILITHIUM2_CLEAN_crypto_sign_signature\0")?,
+                dilithium2_verify: resolve(&library, b"PQCLEAN_DILITHIUM2_CLEAN_crypto_sign_verify\0")?,
+                dilithium3_keypair: resolve(&library, b"PQCLEAN_DILITHIUM3_CLEAN_crypto_sign_keypair\0")?,
+                dilithium3_sign: resolve(&library, b"PQCLEAN_DILITHIUM3_CLEAN_crypto_sign_signature\0")?,
+                dilithium3_verify: resolve(&library, b"PQCLEAN_DILITHIUM3_CLEAN_crypto_sign_verify\0")?,
+                dilithium5_keypair: resolve(&library, b"PQCLEAN_DILITHIUM5_CLEAN_crypto_sign_keypair\0")?,
+                dilithium5_sign: resolve(&library, b"PQCLEAN_DILITHIUM5_CLEAN_crypto_sign_signature\0")?,
+                dilithium5_verify: resolve(&library, b"PQCLEAN_DILITHIUM5_CLEAN_crypto_sign_verify\0")?,
+                _library: library,
+            })
+        }
+    }
+
+    /// Bare name of the native library for the current platform, e.g.
+    /// `libpqc.so` on Linux.
+    fn native_lib_name() -> &'static str {
+        if cfg!(target_os = "windows") {
+            "pqc.dll"
+        } else if cfg!(target_os = "macos") {
+            "libpqc.dylib"
+        } else {
+            "libpqc.so"
+        }
+    }
+
+    /// Candidate paths to try opening, in search order.
+    fn candidate_paths() -> Vec<PathBuf> {
+        let lib_name = Self::native_lib_name();
+        let mut candidates = Vec::new();
+
+        if let Ok(dir) = env::var(LIB_DIR_ENV) {
+            candidates.push(PathBuf::from(dir).join(lib_name));
+        }
+
+        // A bare name defers to the OS loader's own search path.
+        candidates.push(PathBuf::from(lib_name));
+
+        if let Ok(bins_path) = crate::find_binary_path() {
+            candidates.push(bins_path);
+        }
+
+        candidates
+    }
+
+    fn open() -> PqcResult<Self> {
+        let mut attempted = Vec::new();
+        for candidate in Self::candidate_paths() {
+            match Self::load_from(&candidate) {
+                Ok(handle) => return Ok(handle),
+                Err(e) => attempted.push(e.to_string()),
+            }
+        }
+
+        Err(PqcError::LibraryNotFound(format!(
+            "no candidate succeeded: [{}]",
+            attempted.join("; ")
+        )))
+    }
+}
+
+static HANDLE: OnceLock<Result<Handle, String>> = OnceLock::new();
+
+/// Get the lazily-initialized, process-wide native library handle,
+/// opening and resolving it on first use.
+pub fn handle() -> PqcResult<&'static Handle> {
+    HANDLE
+        .get_or_init(|| Handle::open().map_err(|e| e.to_string()))
+        .as_ref()
+        .map_err(|e| PqcError::LibraryNotFound(e.clone()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_candidate_paths_prefers_lib_dir_env() {
+        env::set_var(LIB_DIR_ENV, "/tmp/pqchub-test-libs");
+        let candidates = Handle::candidate_paths();
+        env::remove_var(LIB_DIR_ENV);
+
+        assert_eq!(
+            candidates[0],
+            PathBuf::from("/tmp/pqchub-test-libs").join(Handle::native_lib_name())
+        );
+    }
+
+    #[test]
+    fn test_handle_reports_missing_symbols_or_library() {
+        // No native library is present in the test environment, so every
+        // candidate should fail and `handle()` should surface that as
+        // `LibraryNotFound` rather than panicking.
+        assert!(handle().is_err());
+    }
+}
@@ -0,0 +1,59 @@
+//! Helper macro for length-checked byte-wrapper types.
+//!
+//! Mirrors the `simple_struct!` pattern used by pqcrypto-kyber: every key,
+//! ciphertext and signature type generated by this macro is a thin
+//! `Vec<u8>` wrapper whose only job is to stop callers from passing the
+//! wrong kind of buffer to the wrong function.
+
+/// Generate a length-checked newtype wrapping a `Vec<u8>`.
+///
+/// `$name` is the type to define and `$len` is the expected byte length,
+/// normally one of the `*_BYTES` constants on the owning algorithm struct.
+///
+/// Uses `$crate::`-qualified paths throughout: `macro_rules!` resolves
+/// free-function names at the invocation site, not here, and callers in
+/// `kyber.rs`/`dilithium.rs` don't import `validate_length`.
+macro_rules! simple_struct {
+    ($name:ident, $len:expr) => {
+        #[doc = concat!("A length-checked `", stringify!($name), "` buffer.")]
+        #[derive(Clone, Debug, PartialEq, Eq)]
+        pub struct $name(Vec<u8>);
+
+        impl $name {
+            /// Expected length of this buffer, in bytes.
+            pub const BYTES: usize = $len;
+
+            /// Wrap raw bytes, validating their length.
+            pub fn from_bytes(bytes: &[u8]) -> $crate::PqcResult<Self> {
+                $crate::validate_length(bytes, Self::BYTES, stringify!($name))?;
+                Ok(Self(bytes.to_vec()))
+            }
+
+            /// Borrow the wrapped bytes.
+            pub fn as_bytes(&self) -> &[u8] {
+                &self.0
+            }
+
+            /// Consume the wrapper, returning the owned bytes.
+            pub fn into_bytes(self) -> Vec<u8> {
+                self.0
+            }
+
+            /// Wrap raw bytes without validating their length.
+            ///
+            /// Only used internally, right after an FFI call has filled a
+            /// correctly-sized buffer.
+            pub(crate) fn from_vec_unchecked(bytes: Vec<u8>) -> Self {
+                Self(bytes)
+            }
+        }
+
+        impl AsRef<[u8]> for $name {
+            fn as_ref(&self) -> &[u8] {
+                &self.0
+            }
+        }
+    };
+}
+
+pub(crate) use simple_struct;
@@ -0,0 +1,63 @@
+//! Generic trait layer over the concrete Kyber and Dilithium types.
+//!
+//! These traits let code be written once against "a KEM" or "a signature
+//! scheme" instead of matching on a specific algorithm struct. Every Kyber
+//! type implements [`Kem`] and every Dilithium type implements [`Signature`].
+
+use crate::PqcResult;
+
+/// A post-quantum Key Encapsulation Mechanism.
+pub trait Kem {
+    /// Public key type for this algorithm.
+    type PublicKey;
+    /// Secret key type for this algorithm.
+    type SecretKey;
+    /// Ciphertext (KEM encapsulation) type for this algorithm.
+    type Ciphertext;
+    /// Shared secret type produced by encapsulation/decapsulation.
+    type SharedSecret;
+
+    /// Size of [`Kem::PublicKey`] in bytes.
+    const PUBLIC_KEY_BYTES: usize;
+    /// Size of [`Kem::SecretKey`] in bytes.
+    const SECRET_KEY_BYTES: usize;
+    /// Size of [`Kem::Ciphertext`] in bytes.
+    const CIPHERTEXT_BYTES: usize;
+    /// Size of [`Kem::SharedSecret`] in bytes.
+    const SHARED_SECRET_BYTES: usize;
+
+    /// Generate a fresh key pair.
+    fn keypair(&self) -> PqcResult<(Self::PublicKey, Self::SecretKey)>;
+
+    /// Encapsulate a shared secret under `public_key`.
+    fn encapsulate(&self, public_key: &Self::PublicKey) -> PqcResult<(Self::Ciphertext, Self::SharedSecret)>;
+
+    /// Decapsulate `ciphertext` with `secret_key`, recovering the shared secret.
+    fn decapsulate(&self, ciphertext: &Self::Ciphertext, secret_key: &Self::SecretKey) -> PqcResult<Self::SharedSecret>;
+}
+
+/// A post-quantum digital signature scheme.
+pub trait Signature {
+    /// Public key type for this algorithm.
+    type PublicKey;
+    /// Secret key type for this algorithm.
+    type SecretKey;
+    /// Detached signature type for this algorithm.
+    type DetachedSignature;
+
+    /// Size of [`Signature::PublicKey`] in bytes.
+    const PUBLIC_KEY_BYTES: usize;
+    /// Size of [`Signature::SecretKey`] in bytes.
+    const SECRET_KEY_BYTES: usize;
+    /// Maximum size of [`Signature::DetachedSignature`] in bytes.
+    const SIGNATURE_BYTES: usize;
+
+    /// Generate a fresh key pair.
+    fn keypair(&self) -> PqcResult<(Self::PublicKey, Self::SecretKey)>;
+
+    /// Sign `message` with `secret_key`, producing a detached signature.
+    fn sign(&self, message: &[u8], secret_key: &Self::SecretKey) -> PqcResult<Self::DetachedSignature>;
+
+    /// Verify `signature` over `message` under `public_key`.
+    fn verify(&self, message: &[u8], signature: &Self::DetachedSignature, public_key: &Self::PublicKey) -> PqcResult<bool>;
+}